@@ -1,6 +1,14 @@
 pub mod model;
 pub mod parse;
+pub mod render;
+mod url;
 extern crate derive_builder;
 
 pub use model::*;
-pub use parse::parse_html;
+pub use parse::{
+    parse_all_items_html, parse_content_html, parse_html, parse_html_with_options, HtmlParseError,
+    ParseOptions, ParseOptionsBuilder,
+};
+#[cfg(feature = "highlight")]
+pub use render::{highlight, Highlight, Theme};
+pub use render::{AnsiOptions, AnsiOptionsBuilder, ColorSupport, StyledLine};
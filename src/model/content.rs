@@ -51,6 +51,86 @@ impl fmt::Debug for TextStyle {
     }
 }
 
+impl TextStyle {
+    pub fn with_bold(mut self, enabled: bool) -> Self {
+        self.bold = Some(enabled);
+        self
+    }
+
+    pub fn with_code(mut self, enabled: bool) -> Self {
+        self.code = Some(enabled);
+        self
+    }
+
+    pub fn with_italic(mut self, enabled: bool) -> Self {
+        self.italic = Some(enabled);
+        self
+    }
+
+    pub fn with_strike_through(mut self, enabled: bool) -> Self {
+        self.strike_through = Some(enabled);
+        self
+    }
+
+    pub fn with_underline(mut self, enabled: bool) -> Self {
+        self.underline = Some(enabled);
+        self
+    }
+
+    pub fn with_foreground_rgb(mut self, rgb: (u8, u8, u8)) -> Self {
+        self.foreground_rgb = Some(rgb);
+        self
+    }
+
+    pub fn with_background_rgb(mut self, rgb: (u8, u8, u8)) -> Self {
+        self.background_rgb = Some(rgb);
+        self
+    }
+
+    pub fn is_bold(&self) -> bool {
+        self.bold.unwrap_or(false)
+    }
+
+    pub fn is_code(&self) -> bool {
+        self.code.unwrap_or(false)
+    }
+
+    pub fn is_italic(&self) -> bool {
+        self.italic.unwrap_or(false)
+    }
+
+    pub fn is_strike_through(&self) -> bool {
+        self.strike_through.unwrap_or(false)
+    }
+
+    pub fn is_underline(&self) -> bool {
+        self.underline.unwrap_or(false)
+    }
+
+    pub fn foreground_rgb(&self) -> Option<(u8, u8, u8)> {
+        self.foreground_rgb
+    }
+
+    pub fn background_rgb(&self) -> Option<(u8, u8, u8)> {
+        self.background_rgb
+    }
+
+    // Fields set on self win,
+    // unset fields are taken from the parent.
+    // "<em>a <strong>b</strong></em>" makes "b" bold and italic.
+    pub fn inherit(self, parent: &TextStyle) -> Self {
+        Self {
+            bold: self.bold.or(parent.bold),
+            code: self.code.or(parent.code),
+            italic: self.italic.or(parent.italic),
+            strike_through: self.strike_through.or(parent.strike_through),
+            underline: self.underline.or(parent.underline),
+            foreground_rgb: self.foreground_rgb.or(parent.foreground_rgb),
+            background_rgb: self.background_rgb.or(parent.background_rgb),
+        }
+    }
+}
+
 // Example:
 // "a <code>b</code> c"
 // would consist of three text atomics:
@@ -147,6 +227,69 @@ impl From<TextAtomicBuilder> for TextAtomic {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TableAlignment {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableCell {
+    pub content: Vec<TextAtomic>,
+    pub header: bool, // <th> instead of <td>
+    pub alignment: Option<TableAlignment>,
+    pub colspan: u32,
+    pub rowspan: u32,
+}
+
+impl TableCell {
+    pub fn new(content: Vec<TextAtomic>) -> Self {
+        Self {
+            content,
+            header: false,
+            alignment: None,
+            colspan: 1,
+            rowspan: 1,
+        }
+    }
+    pub fn with_header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+    pub fn with_alignment(mut self, alignment: Option<TableAlignment>) -> Self {
+        self.alignment = alignment;
+        self
+    }
+    pub fn with_span(mut self, colspan: u32, rowspan: u32) -> Self {
+        self.colspan = colspan;
+        self.rowspan = rowspan;
+        self
+    }
+}
+
+// Vec of rows.
+// Rows are Vec of Cells.
+// Header rows usually come from <thead>
+// and are kept apart from the body rows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Table {
+    pub header: Vec<Vec<TableCell>>,
+    pub body: Vec<Vec<TableCell>>,
+}
+
+// Banners rustdoc puts in front of
+// or into the documentation of an item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CalloutKind {
+    Warning,     // <div class="warning">
+    Note,        // <div class="note">
+    Deprecated,  // "Deprecated since 1.2.0: use y instead"
+    Unstable,    // "This is a nightly-only experimental API."
+    Portability, // "Available on crate feature std only."
+    EmptyImpl,   // "This impl is empty."
+}
+
 // Something which can not be embedded inline.
 // For example a table, an image or a list.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -160,29 +303,40 @@ pub enum BlockContainer {
     Quote(Vec<BlockContainer>),
     // Code block, not inline code.
     // Style will be ignored in favor of
-    // formatting the entire code at once.
+    // formatting the entire code at once,
+    // see highlight() of the "highlight" feature.
     Code {
         code: String,
         language: Option<Language>,
     },
+    // Every point may consist of multiple blocks,
+    // e.g. a paragraph followed by a nested list.
     BulletPoints {
-        points: Vec<BlockContainer>,
-        enumerated: bool, // points or 1. 2. 3.
+        points: Vec<Vec<BlockContainer>>,
+        enumerated: bool,   // points or 1. 2. 3.
+        start: Option<u64>, // first number, None if not enumerated
+    },
+    Table(Table),
+    // Deprecation and feature gate notices,
+    // warnings, etc.
+    Callout {
+        kind: CalloutKind,
+        content: Vec<BlockContainer>,
     },
-    // Vec of rows.
-    // Rows are Vec of Cells.
-    // Cells are Text.
-    Table(Vec<Vec<Vec<TextAtomic>>>),
     // This one is not renderable in a terminal,
     // but it has to be represented somehow anyways.
     Image {
         url: String,
         alt: Option<String>,
+        title: Option<String>,
+        width: Option<u32>, // in pixels
+        height: Option<u32>,
+        link: Option<String>, // of a surrounding <a>, e.g. badges
     },
 }
 
 fn merge<T>(a: Vec<T>, b: Vec<T>) -> Vec<T> {
-    a.into_iter().chain(b.into_iter()).collect::<Vec<T>>()
+    a.into_iter().chain(b).collect::<Vec<T>>()
 }
 
 pub enum BlockContainerAddition {
@@ -0,0 +1,139 @@
+use crate::{Content, TextAtomic};
+
+// Structured form of the item declaration on top of
+// every item page, e.g. `pub struct Error { /* private fields */ }`.
+//
+// Types are kept as text atomics, so every type
+// mentioned in a signature still links to its page.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenericParamKind {
+    Lifetime, // 'a
+    Type,     // T
+    Const,    // const N: usize
+}
+
+// <'a, T: Clone + 'a = String, const N: usize>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenericParam {
+    pub name: String, // "'a", "T" or "N"
+    pub kind: GenericParamKind,
+    pub bounds: Vec<TextAtomic>, // "Clone + 'a", the type for const generics
+    pub default: Option<Vec<TextAtomic>>,
+}
+
+// where T: Clone, for<'a> &'a T: Add
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WherePredicate {
+    pub bounded: Vec<TextAtomic>, // "T"
+    pub bounds: Vec<TextAtomic>,  // "Clone"
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub visibility: Option<String>,
+    pub name: String, // "0", "1", ... for tuple fields
+    pub ty: Vec<TextAtomic>,
+    pub docs: Option<Content>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldsStyle {
+    Unit,  // struct A;
+    Tuple, // struct A(u32);
+    Named, // struct A { a: u32 }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fields {
+    pub style: FieldsStyle,
+    pub fields: Vec<Field>,
+    // /* private fields */, _ or /* fields omitted */
+    pub has_private_fields: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    pub name: String,
+    pub fields: Fields,
+    pub discriminant: Option<String>, // A = 1
+    pub docs: Option<Content>,
+}
+
+// fn (&self, x: u32)
+// Receivers without a type, like &mut self,
+// are named "self" and keep the full text as type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Argument {
+    pub name: String,
+    pub ty: Vec<TextAtomic>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionSignature {
+    pub qualifiers: Vec<String>, // const, async, unsafe, extern "C"
+    pub name: String,
+    pub generics: Vec<GenericParam>,
+    pub arguments: Vec<Argument>,
+    pub return_type: Option<Vec<TextAtomic>>,
+    pub where_clause: Vec<WherePredicate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraitItem {
+    Method {
+        signature: FunctionSignature,
+        provided: bool, // has a default implementation
+    },
+    AssociatedType {
+        name: String,
+        bounds: Vec<TextAtomic>,
+        default: Option<Vec<TextAtomic>>,
+    },
+    AssociatedConstant {
+        name: String,
+        ty: Vec<TextAtomic>,
+        default: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeclarationKind {
+    Struct(Fields),
+    Union(Fields),
+    Enum {
+        variants: Vec<Variant>,
+        has_hidden_variants: bool, // "// some variants omitted"
+    },
+    Trait {
+        qualifiers: Vec<String>, // unsafe, auto
+        supertraits: Vec<TextAtomic>,
+        items: Vec<TraitItem>,
+    },
+    Function(FunctionSignature),
+    TypeAlias {
+        ty: Option<Vec<TextAtomic>>,
+    },
+    Constant {
+        ty: Vec<TextAtomic>,
+        value: Option<String>,
+    },
+    Static {
+        mutable: bool,
+        ty: Vec<TextAtomic>,
+        value: Option<String>,
+    },
+    // Macros and whatever else we do not understand.
+    // Holds the plain declaration.
+    Other(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemDeclaration {
+    pub attributes: Vec<String>,    // ["#[non_exhaustive]"]
+    pub visibility: Option<String>, // "pub", "pub(crate)"
+    pub name: String,
+    pub generics: Vec<GenericParam>,
+    pub where_clause: Vec<WherePredicate>,
+    pub kind: DeclarationKind,
+}
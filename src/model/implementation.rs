@@ -0,0 +1,143 @@
+use crate::{BlockContainer, Content, DocuPage, GenericParam, TextAtomic, WherePredicate};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImplKind {
+    Inherent, // impl Error
+    Trait,    // impl Debug for Error
+    Auto,     // impl Send for Error, generated by the compiler
+    Blanket,  // impl<T> From<T> for T
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssociatedItemKind {
+    Method,
+    Constant,
+    Type,
+}
+
+// The banners rustdoc shows above the docs of an item.
+// The texts are taken as shown, e.g.
+// "Deprecated since 1.42.0: use the Display impl or to_string()"
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stability {
+    pub stable_since: Option<String>, // "1.0.0", only given for the standard library
+    pub deprecated: Option<String>,
+    pub unstable: Option<String>,
+    pub portability: Option<String>, // "Available on crate feature std only."
+}
+
+impl Stability {
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated.is_some()
+    }
+
+    pub fn is_unstable(&self) -> bool {
+        self.unstable.is_some()
+    }
+}
+
+// A method, constant or type inside an impl block
+// or declared by a trait.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssociatedItem {
+    pub id: String, // "method.new", the anchor on the page
+    pub kind: AssociatedItemKind,
+    pub name: String,
+    pub signature: Vec<TextAtomic>,
+    pub docs: Option<Content>,
+    pub stability: Stability,
+    pub source: Option<String>,
+}
+
+impl AssociatedItem {
+    // The first paragraph of the docs,
+    // without the "Read more" link of trait impls.
+    pub fn summary(&self) -> Option<String> {
+        let docs = self.docs.as_ref()?;
+        let BlockContainer::Paragraph(atomics) = docs.0.first()? else {
+            return None;
+        };
+        let summary = atomics
+            .iter()
+            .filter(|a| a.text.trim() != "Read more")
+            .map(|a| a.text.as_str())
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(summary).filter(|s| !s.is_empty())
+    }
+
+    // Trait methods without a default implementation.
+    pub fn is_required(&self) -> bool {
+        self.id.starts_with("tymethod.")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Implementation {
+    pub id: Option<String>, // "impl-Debug"
+    pub kind: ImplKind,
+    pub negative: bool, // impl !Send for Error
+    pub generics: Vec<GenericParam>,
+    pub implemented_trait: Option<Vec<TextAtomic>>,
+    pub self_type: Vec<TextAtomic>,
+    pub where_clause: Vec<WherePredicate>,
+    pub source: Option<String>,
+    pub items: Vec<AssociatedItem>,
+}
+
+impl Implementation {
+    // "From<Error>"
+    pub fn trait_text(&self) -> Option<String> {
+        self.implemented_trait
+            .as_ref()
+            .map(|atomics| atomics.iter().map(|a| a.text.as_str()).collect())
+    }
+
+    // "From", without generic arguments or path
+    pub fn trait_name(&self) -> Option<String> {
+        let text = self.trait_text()?;
+        let path = text.split('<').next().unwrap_or_default();
+        Some(
+            path.rsplit("::")
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+        )
+    }
+}
+
+impl DocuPage {
+    // All traits the item implements,
+    // negative impls like `impl !Send` are left out.
+    pub fn implemented_traits(&self) -> Vec<String> {
+        self.implementations
+            .iter()
+            .filter(|i| !i.negative)
+            .filter_map(|i| i.trait_text())
+            .collect()
+    }
+
+    // Items declared by a trait come first,
+    // followed by the items of every impl block.
+    pub fn associated_items(&self) -> Vec<&AssociatedItem> {
+        self.trait_items
+            .iter()
+            .chain(self.implementations.iter().flat_map(|i| i.items.iter()))
+            .collect()
+    }
+
+    // Takes the anchor with or without "#", e.g. "#method.new"
+    pub fn associated_item(&self, id: &str) -> Option<&AssociatedItem> {
+        let id = id.trim_start_matches('#');
+        self.associated_items().into_iter().find(|i| i.id == id)
+    }
+
+    pub fn implements(&self, trait_name: &str) -> bool {
+        self.implementations
+            .iter()
+            .filter(|i| !i.negative)
+            .any(|i| i.trait_name().as_deref() == Some(trait_name))
+    }
+}
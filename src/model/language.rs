@@ -39,6 +39,7 @@ pub enum Language {
 }
 
 impl Language {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(name: &str) -> Option<Self> {
         match name.to_lowercase().as_str() {
             "bash" | "shell" | "sh" => Some(Self::Bash),
@@ -68,4 +69,34 @@ impl Language {
             _ => None,
         }
     }
+
+    // Name as used for code fences, e.g. ```rust
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bash => "bash",
+            Self::C => "c",
+            Self::Cpp => "cpp",
+            Self::Go => "go",
+            Self::Html => "html",
+            Self::Ini => "ini",
+            Self::Java => "java",
+            Self::JavaScript => "javascript",
+            Self::Json => "json",
+            Self::Kotlin => "kotlin",
+            Self::Lua => "lua",
+            Self::Matlab => "matlab",
+            Self::Perl => "perl",
+            Self::Php => "php",
+            Self::Python => "python",
+            Self::R => "r",
+            Self::Ruby => "ruby",
+            Self::Rust => "rust",
+            Self::Sql => "sql",
+            Self::Swift => "swift",
+            Self::Toml => "toml",
+            Self::TypeScript => "typescript",
+            Self::Xml => "xml",
+            Self::Yaml => "yaml",
+        }
+    }
 }
@@ -0,0 +1,37 @@
+use crate::{DocsType, TextAtomic};
+
+// One row of an item table on a module page,
+// e.g. "Error - Error type of random number generators".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListedItem {
+    pub name: String,
+    pub url: String, // relative to the listing page, unless links are resolved
+    pub summary: Vec<TextAtomic>,
+    // Markers next to the name, like "Deprecated",
+    // "Experimental" or the feature the item needs.
+    pub tags: Vec<String>,
+}
+
+// All listed items of one kind, e.g. the "Structs" section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemListing {
+    pub kind: DocsType,
+    pub items: Vec<ListedItem>,
+}
+
+impl ItemListing {
+    // Keeps the order in which the kinds appear first.
+    pub fn group(items: Vec<(DocsType, ListedItem)>) -> Vec<Self> {
+        let mut listings: Vec<Self> = vec![];
+        for (kind, item) in items {
+            match listings.iter_mut().find(|l| l.kind == kind) {
+                Some(listing) => listing.items.push(item),
+                None => listings.push(Self {
+                    kind,
+                    items: vec![item],
+                }),
+            }
+        }
+        listings
+    }
+}
@@ -0,0 +1,258 @@
+use std::path::PathBuf;
+
+use crate::{url::join_url, CrateVersion, DocsType, TextAtomic};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DocuSource {
+    // DocsRs url might be "https://docs.rs/serde/1.0.152/serde/"
+    DocsRs { url: String },
+    // After running `cargo doc`, documentation
+    // of already installed packages
+    // can be parsed from filesystem
+    Local { filepath: Box<std::path::PathBuf> },
+}
+
+// Where a page lives and what it documents.
+// "https://docs.rs/serde/1.0.152/serde/de/trait.Deserialize.html" is
+// crate serde, version 1.0.152, module path serde::de,
+// item kind trait and item name Deserialize.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageLocation {
+    pub crate_name: String,
+    pub crate_version: CrateVersion,
+    pub source: DocuSource,
+    pub platform: Option<String>, // docs.rs target, e.g. "x86_64-pc-windows-msvc"
+    pub module_path: Vec<String>, // ["serde", "de"], including the crate root
+    pub item_kind: DocsType,
+    pub item_name: Option<String>, // None for module and crate pages
+}
+
+// What a link on a documentation page points to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkKind {
+    SamePageAnchor, // "#method.new"
+    SameCrateItem,  // "struct.Error.html"
+    OtherCrateItem, // "../rand_core/index.html"
+    StdItem,        // std, core, alloc, proc_macro and test
+    SourceView,     // "../src/rand/lib.rs.html#1-10"
+    WebLink,        // anything else
+}
+
+const DOCS_RS: &str = "https://docs.rs";
+const STD_CRATES: [&str; 5] = ["std", "core", "alloc", "proc_macro", "test"];
+
+fn strip_docs_rs(url: &str) -> Option<&str> {
+    ["https://docs.rs/", "http://docs.rs/", "//docs.rs/"]
+        .iter()
+        .find_map(|prefix| url.strip_prefix(prefix))
+}
+
+// Module directories followed by the page file,
+// e.g. ["serde", "de", "trait.Deserialize.html"].
+fn parse_item_path(segments: &[&str]) -> Option<(Vec<String>, DocsType, Option<String>)> {
+    let (file, directories) = segments.split_last()?;
+    let module_path = directories
+        .iter()
+        .filter(|d| !d.is_empty())
+        .map(|d| d.to_string())
+        .collect::<Vec<String>>();
+    // Source views and pages like settings.html are no items
+    if module_path.is_empty() || module_path[0] == "src" {
+        return None;
+    }
+    if file.is_empty() || *file == "index.html" {
+        let kind = match module_path.len() {
+            1 => DocsType::Crate,
+            _ => DocsType::Module,
+        };
+        return Some((module_path, kind, None));
+    }
+    let (kind, name) = file.strip_suffix(".html")?.split_once('.')?;
+    let kind = DocsType::from_rustdoc_str(kind)?;
+    Some((module_path, kind, Some(name.to_string())))
+}
+
+// docs.rs platforms are target triples like
+// "x86_64-unknown-linux-gnu". Modules can not contain dashes.
+fn is_platform(segment: &str) -> bool {
+    segment.contains('-')
+}
+
+impl PageLocation {
+    // Takes either a docs.rs URL or a local file path.
+    pub fn parse(location: &str) -> Option<Self> {
+        match strip_docs_rs(location) {
+            Some(_) => Self::from_docs_rs_url(location),
+            None => Self::from_local_path(location),
+        }
+    }
+
+    // "https://docs.rs/serde/1.0.152/serde/de/trait.Deserialize.html",
+    // "https://docs.rs/rand/latest/x86_64-pc-windows-msvc/rand/",
+    // "https://docs.rs/crate/rand/0.8.5/target-redirect/rand/struct.Error.html"
+    // or just "https://docs.rs/rand".
+    pub fn from_docs_rs_url(url: &str) -> Option<Self> {
+        let path = strip_docs_rs(url)?.split(['?', '#']).next()?;
+        let mut segments = path.split('/').collect::<Vec<&str>>();
+        if segments.first() == Some(&"crate") {
+            if segments.get(3) != Some(&"target-redirect") {
+                return None;
+            }
+            segments.remove(3);
+            segments.remove(0);
+        }
+
+        let crate_name = segments.first().filter(|s| !s.is_empty())?.to_string();
+        // Requirements like "^1" are resolved to the
+        // newest matching version by docs.rs.
+        let crate_version = segments
+            .get(1)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(CrateVersion::Latest);
+        let mut rest = segments.get(2..).unwrap_or_default().to_vec();
+        let platform = match rest.first() {
+            Some(p) if is_platform(p) => Some(rest.remove(0).to_string()),
+            _ => None,
+        };
+        // docs.rs redirects to the crate root
+        let root_module = crate_name.replace('-', "_");
+        if rest.iter().all(|s| s.is_empty()) {
+            rest = vec![root_module.as_str(), ""];
+        }
+
+        let (module_path, item_kind, item_name) = parse_item_path(&rest)?;
+        Some(Self {
+            crate_name,
+            crate_version,
+            source: DocuSource::DocsRs {
+                url: url.to_string(),
+            },
+            platform,
+            module_path,
+            item_kind,
+            item_name,
+        })
+    }
+
+    // "target/doc/serde/de/trait.Deserialize.html"
+    // or any other path into a `cargo doc` output directory.
+    pub fn from_local_path(path: &str) -> Option<Self> {
+        let trimmed = path.strip_prefix("file://").unwrap_or(path);
+        let segments = trimmed.split(['/', '\\']).collect::<Vec<&str>>();
+        // Prefer "target/doc", crates might have a module named doc
+        let doc = segments
+            .windows(2)
+            .position(|w| w == ["target", "doc"])
+            .map(|i| i + 1)
+            .or_else(|| segments.iter().rposition(|s| *s == "doc"))
+            .filter(|i| i + 2 < segments.len())?;
+        let (module_path, item_kind, item_name) = parse_item_path(&segments[doc + 1..])?;
+        Some(Self {
+            // The directory is named after the root module,
+            // which matches the crate name up to dashes.
+            crate_name: module_path[0].clone(),
+            crate_version: CrateVersion::Latest,
+            source: DocuSource::Local {
+                filepath: Box::new(PathBuf::from(trimmed)),
+            },
+            platform: None,
+            module_path,
+            item_kind,
+            item_name,
+        })
+    }
+
+    // The location this page was parsed from,
+    // relative links are relative to it.
+    pub fn base_url(&self) -> String {
+        match &self.source {
+            DocuSource::DocsRs { url } => url.clone(),
+            DocuSource::Local { filepath } => filepath.to_string_lossy().to_string(),
+        }
+    }
+
+    // Works for relative and absolute links.
+    pub fn classify_link(&self, link: &str) -> LinkKind {
+        let base = self.base_url();
+        let without_fragment = |url: &str| url.split('#').next().unwrap_or_default().to_string();
+        let absolute = join_url(&base, link);
+        if link.starts_with('#') || without_fragment(&absolute) == without_fragment(&base) {
+            return LinkKind::SamePageAnchor;
+        }
+
+        let path = without_fragment(&absolute);
+        let path = path.split('?').next().unwrap_or_default();
+        let segments = path.split('/').collect::<Vec<&str>>();
+        if path.ends_with(".rs.html") && segments.contains(&"src") {
+            return LinkKind::SourceView;
+        }
+
+        // https://doc.rust-lang.org/nightly/std/primitive.u32.html
+        if let Some(rest) = absolute.split_once("://doc.rust-lang.org/").map(|(_, r)| r) {
+            let mut parts = rest.split('/');
+            let first = parts.next().unwrap_or_default();
+            let second = parts.next().unwrap_or_default();
+            if STD_CRATES.contains(&first) || STD_CRATES.contains(&second) {
+                return LinkKind::StdItem;
+            }
+            return LinkKind::WebLink;
+        }
+
+        match Self::parse(&absolute) {
+            Some(other) if STD_CRATES.contains(&other.module_path[0].as_str()) => LinkKind::StdItem,
+            Some(other) if other.module_path[0] == self.module_path[0] => LinkKind::SameCrateItem,
+            Some(_) => LinkKind::OtherCrateItem,
+            None => LinkKind::WebLink,
+        }
+    }
+
+    fn filename(&self) -> String {
+        match &self.item_name {
+            Some(name) => format!("{}.{}.html", self.item_kind.as_rustdoc_str(), name),
+            None => "index.html".to_string(),
+        }
+    }
+
+    // Inverse of parse.
+    // Builds the canonical docs.rs URL or the local file path
+    // of the page, without any fragment.
+    pub fn to_url(&self) -> String {
+        let mut segments = self.module_path.clone();
+        segments.push(self.filename());
+        match &self.source {
+            DocuSource::DocsRs { .. } => {
+                let platform = match &self.platform {
+                    Some(p) => format!("{}/", p),
+                    None => "".to_string(),
+                };
+                format!(
+                    "{}/{}/{}/{}{}",
+                    DOCS_RS,
+                    self.crate_name,
+                    self.crate_version,
+                    platform,
+                    segments.join("/")
+                )
+            }
+            DocuSource::Local { filepath } => {
+                // Replace the module directories and the file
+                // of the original path, the rest is the doc root.
+                let path = filepath.to_string_lossy().replace('\\', "/");
+                let original = path.split('/').collect::<Vec<&str>>();
+                let root_len = original.len().saturating_sub(self.module_path.len() + 1);
+                let mut root = original[..root_len].join("/");
+                if !root.is_empty() {
+                    root.push('/');
+                }
+                format!("{}{}", root, segments.join("/"))
+            }
+        }
+    }
+}
+
+impl TextAtomic {
+    // None if the text is not linked at all.
+    pub fn link_kind(&self, location: &PageLocation) -> Option<LinkKind> {
+        self.url.as_ref().map(|url| location.classify_link(url))
+    }
+}
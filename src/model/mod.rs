@@ -1,6 +1,18 @@
 pub mod content;
+pub mod declaration;
+pub mod implementation;
 pub mod language;
+pub mod listing;
+pub mod location;
 pub mod page;
+pub mod sidebar;
+pub mod version;
 pub use content::*;
+pub use declaration::*;
+pub use implementation::*;
 pub use language::*;
+pub use listing::*;
+pub use location::*;
 pub use page::*;
+pub use sidebar::*;
+pub use version::*;
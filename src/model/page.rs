@@ -1,55 +1,139 @@
 use serde::{Deserialize, Serialize};
 
-use crate::Content;
+use crate::{
+    AssociatedItem, Content, CrateVersion, Implementation, ItemDeclaration, ItemListing,
+    PageLocation, Sidebar,
+};
 
 /// DocuPage is an abstract syntax tree and contains information
 /// as well as meta information about a typical page from docs.rs.
 /// An example page would be docs.rs/serde/1.0.152/serde/.
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum DocuSource {
-    // DocsRs url might be "https://docs.rs/serde/1.0.152/serde/"
-    DocsRs { url: String },
-    // After running `cargo doc`, documentation
-    // of already installed packages
-    // can be parsed from filesystem
-    Local { filepath: Box<std::path::PathBuf> },
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum CrateVersion {
-    // For links like
-    // https://docs.rs/html_parser/latest/
-    // or crates specified by a file system location,
-    // git url without commit, the CrateVersion
-    // has to be latest
-    Latest,
-    // 1.2.3-rc5
-    Semantic {
-        major: u64,             // 1
-        minor: u64,             // 2
-        patch: u64,             // 3
-        suffix: Option<String>, // Some("rc5")
-    },
-    GitCommit {
-        hash: String,
-    },
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct PageLocation {
-    pub crate_name: String,
-    pub crate_version: CrateVersion,
-    pub source: DocuSource,
-}
-
+// Every kind of item rustdoc generates a page for.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DocsType {
+    AssociatedType,
+    AttributeMacro, // #[proc_macro_attribute]
+    Constant,
     Crate,
+    DeriveMacro, // #[proc_macro_derive]
     Enum,
+    ForeignType, // extern { type Foo; }
+    Function,
+    Keyword,
+    Macro, // macro_rules! and function-like proc macros
     Module,
+    Primitive,
+    Static,
     Struct,
     Trait,
+    TraitAlias,
+    TypeAlias,
+    Union,
+}
+
+impl DocsType {
+    // Short names rustdoc uses for body classes
+    // and filenames, like "struct" in struct.Error.html.
+    pub fn from_rustdoc_str(name: &str) -> Option<Self> {
+        match name {
+            "associatedtype" => Some(Self::AssociatedType),
+            "attr" => Some(Self::AttributeMacro),
+            "constant" => Some(Self::Constant),
+            "derive" => Some(Self::DeriveMacro),
+            "enum" => Some(Self::Enum),
+            "foreigntype" => Some(Self::ForeignType),
+            "fn" => Some(Self::Function),
+            "keyword" => Some(Self::Keyword),
+            "macro" => Some(Self::Macro),
+            "mod" => Some(Self::Module),
+            "primitive" => Some(Self::Primitive),
+            "static" => Some(Self::Static),
+            "struct" => Some(Self::Struct),
+            "trait" => Some(Self::Trait),
+            "traitalias" => Some(Self::TraitAlias),
+            "type" => Some(Self::TypeAlias),
+            "union" => Some(Self::Union),
+            _ => None,
+        }
+    }
+
+    // Rustdoc puts the type on the body,
+    // e.g. <body class="rustdoc struct">.
+    // Crate roots are "rustdoc mod crate".
+    pub fn from_rustdoc_class(class: &str) -> Option<Self> {
+        match class {
+            "crate" => Some(Self::Crate),
+            _ => Self::from_rustdoc_str(class),
+        }
+    }
+
+    // Titles look like "Struct rand::Error"
+    // or "Attribute Macro tokio::main".
+    pub fn from_title(title: &str) -> Option<Self> {
+        // Longer prefixes first, "Type" alone is no page type.
+        [
+            ("Associated Type ", Self::AssociatedType),
+            ("Attribute Macro ", Self::AttributeMacro),
+            ("Derive Macro ", Self::DeriveMacro),
+            ("Foreign Type ", Self::ForeignType),
+            ("Primitive Type ", Self::Primitive),
+            ("Trait Alias ", Self::TraitAlias),
+            ("Type Alias ", Self::TypeAlias),
+            ("Type Definition ", Self::TypeAlias),
+            ("Constant ", Self::Constant),
+            ("Crate ", Self::Crate),
+            ("Enum ", Self::Enum),
+            ("Function ", Self::Function),
+            ("Keyword ", Self::Keyword),
+            ("Macro ", Self::Macro),
+            ("Module ", Self::Module),
+            ("Static ", Self::Static),
+            ("Struct ", Self::Struct),
+            ("Trait ", Self::Trait),
+            ("Union ", Self::Union),
+        ]
+        .into_iter()
+        .find(|(prefix, _)| title.trim_start().starts_with(prefix))
+        .map(|(_, page_type)| page_type)
+    }
+
+    // Inverse of from_rustdoc_str.
+    // Crate roots are modules for rustdoc.
+    pub fn as_rustdoc_str(&self) -> &'static str {
+        match self {
+            Self::AssociatedType => "associatedtype",
+            Self::AttributeMacro => "attr",
+            Self::Constant => "constant",
+            Self::Crate | Self::Module => "mod",
+            Self::DeriveMacro => "derive",
+            Self::Enum => "enum",
+            Self::ForeignType => "foreigntype",
+            Self::Function => "fn",
+            Self::Keyword => "keyword",
+            Self::Macro => "macro",
+            Self::Primitive => "primitive",
+            Self::Static => "static",
+            Self::Struct => "struct",
+            Self::Trait => "trait",
+            Self::TraitAlias => "traitalias",
+            Self::TypeAlias => "type",
+            Self::Union => "union",
+        }
+    }
+
+    // "struct.Error.html", "index.html", ...
+    // Crate roots can not be told apart from
+    // modules by their filename alone.
+    pub fn from_filename(filename: &str) -> Option<Self> {
+        if filename == "index.html" {
+            return Some(Self::Module);
+        }
+        match filename.split('.').next()? {
+            "mod" => None,
+            kind => Self::from_rustdoc_str(kind),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -58,10 +142,21 @@ pub struct OwnerReference {
     pub url: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencyKind {
+    Normal,
+    Dev,
+    Build,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DependencyReference {
     pub name: String,
-    pub version: CrateVersion,
+    pub kind: DependencyKind,
+    pub requirement: String, // as written in Cargo.toml, e.g. "^0.4.4"
+    // Lowest version matching the requirement, Latest for "*"
+    // and None for ranges like ">=1, <2".
+    pub version: Option<CrateVersion>,
     pub url: String,
 }
 
@@ -113,6 +208,7 @@ impl Percentage {
     /// Guaranteed to be between 0% and 100%.
     /// Resolution of 1 / 10_000
     /// Returns Err(()) for out of bound values.
+    #[allow(clippy::result_unit_err)]
     pub fn from_f32_ratio(r: f32) -> Result<Self, ()> {
         if !(0. ..=1.).contains(&r) {
             return Err(());
@@ -127,6 +223,13 @@ impl Percentage {
     }
 }
 
+// "45 out of 50 items documented"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentedItems {
+    pub documented: u64,
+    pub total: u64,
+}
+
 // Everything that is there about the documentation page,
 // that has not directly in the markdown styled content.
 // For example type, name, version, links to other
@@ -136,6 +239,7 @@ impl Percentage {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DocuPageMeta {
     pub documentation_percent: Option<Percentage>,
+    pub documented_items: Option<DocumentedItems>,
     pub page_type: DocsType,
     pub references: References,
     pub title: String,
@@ -145,4 +249,15 @@ pub struct DocuPageMeta {
 pub struct DocuPage {
     pub content: Content,
     pub meta: DocuPageMeta,
+    // Modules and crates have none.
+    pub declaration: Option<ItemDeclaration>,
+    pub implementations: Vec<Implementation>,
+    // Required and provided items on trait pages.
+    pub trait_items: Vec<AssociatedItem>,
+    // The item tables of module and crate pages.
+    pub listings: Vec<ItemListing>,
+    pub sidebar: Option<Sidebar>,
+    // Only known if the caller told us
+    // where the page came from.
+    pub location: Option<PageLocation>,
 }
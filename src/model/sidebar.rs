@@ -0,0 +1,37 @@
+use crate::CrateVersion;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidebarLink {
+    pub text: String,
+    pub url: String,
+}
+
+// "Methods", "Trait Implementations", "Fields", ...
+// Module pages have a block of links without a title.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidebarSection {
+    pub title: Option<String>,
+    pub url: Option<String>, // the heading links to the part of the page
+    pub links: Vec<SidebarLink>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sidebar {
+    // The item the page is about, e.g. "Error" or "Crate rand"
+    pub location: Option<SidebarLink>,
+    // Only shown on crate roots and by newer rustdoc versions.
+    pub crate_name: Option<String>,
+    pub crate_version: Option<CrateVersion>,
+    // "In rand", "In crate rand" or "In module rngs",
+    // with the text reduced to the name.
+    pub parents: Vec<SidebarLink>,
+    pub sections: Vec<SidebarSection>,
+}
+
+impl Sidebar {
+    pub fn section(&self, title: &str) -> Option<&SidebarSection> {
+        self.sections
+            .iter()
+            .find(|s| s.title.as_deref() == Some(title))
+    }
+}
@@ -0,0 +1,220 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrateVersion {
+    // For links like
+    // https://docs.rs/html_parser/latest/
+    // or crates specified by a file system location,
+    // git url without commit, the CrateVersion
+    // has to be latest
+    Latest,
+    // 1.2.3-rc.5+build.7
+    Semantic {
+        major: u64,             // 1
+        minor: u64,             // 2
+        patch: u64,             // 3
+        suffix: Option<String>, // Some("rc.5"), the pre-release
+        build: Option<String>,  // Some("build.7"), ignored for precedence
+    },
+    GitCommit {
+        hash: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrateVersionParseError(pub String);
+
+impl fmt::Display for CrateVersionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid crate version: {}", self.0)
+    }
+}
+
+impl std::error::Error for CrateVersionParseError {}
+
+impl CrateVersion {
+    pub fn semantic(major: u64, minor: u64, patch: u64) -> Self {
+        Self::Semantic {
+            major,
+            minor,
+            patch,
+            suffix: None,
+            build: None,
+        }
+    }
+}
+
+// Pre-release and build metadata consist of
+// dot separated, non empty [0-9A-Za-z-] identifiers.
+fn is_valid_identifier_list(s: &str) -> bool {
+    s.split('.').all(|identifier| {
+        !identifier.is_empty()
+            && identifier
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
+// Numbers like "01" are not allowed, neither in
+// the version nor in pre-release identifiers.
+fn has_leading_zero(number: &str) -> bool {
+    number.len() > 1 && number.starts_with('0') && number.chars().all(|c| c.is_ascii_digit())
+}
+
+impl FromStr for CrateVersion {
+    type Err = CrateVersionParseError;
+
+    // "1.2.3-rc.5+meta", "latest", "*"
+    // or a full git commit hash.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || CrateVersionParseError(s.to_string());
+        let s = s.trim();
+        if s == "latest" || s == "*" {
+            return Ok(Self::Latest);
+        }
+        if s.len() == 40 && s.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Ok(Self::GitCommit {
+                hash: s.to_lowercase(),
+            });
+        }
+
+        let (rest, build) = match s.split_once('+') {
+            Some((r, b)) => (r, Some(b.to_string())),
+            None => (s, None),
+        };
+        let (numbers, suffix) = match rest.split_once('-') {
+            Some((n, p)) => (n, Some(p.to_string())),
+            None => (rest, None),
+        };
+        let identifiers = suffix.iter().chain(build.iter());
+        if !identifiers.into_iter().all(|i| is_valid_identifier_list(i)) {
+            return Err(err());
+        }
+        if suffix
+            .iter()
+            .flat_map(|s| s.split('.'))
+            .any(has_leading_zero)
+        {
+            return Err(err());
+        }
+
+        let numbers = numbers
+            .split('.')
+            .map(|n| {
+                if n.is_empty() || !n.chars().all(|c| c.is_ascii_digit()) || has_leading_zero(n) {
+                    return None;
+                }
+                n.parse::<u64>().ok()
+            })
+            .collect::<Option<Vec<u64>>>()
+            .ok_or_else(err)?;
+        match numbers[..] {
+            [major, minor, patch] => Ok(Self::Semantic {
+                major,
+                minor,
+                patch,
+                suffix,
+                build,
+            }),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl fmt::Display for CrateVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Latest => write!(f, "latest"),
+            Self::Semantic {
+                major,
+                minor,
+                patch,
+                suffix,
+                build,
+            } => {
+                write!(f, "{}.{}.{}", major, minor, patch)?;
+                if let Some(suffix) = suffix {
+                    write!(f, "-{}", suffix)?;
+                }
+                if let Some(build) = build {
+                    write!(f, "+{}", build)?;
+                }
+                Ok(())
+            }
+            Self::GitCommit { hash } => write!(f, "{}", hash),
+        }
+    }
+}
+
+// Precedence of pre-releases as defined by semver:
+// 1.0.0-alpha < 1.0.0-alpha.1 < 1.0.0-alpha.beta
+// < 1.0.0-beta.2 < 1.0.0-beta.11 < 1.0.0-rc.1 < 1.0.0
+fn compare_pre_releases(a: &Option<String>, b: &Option<String>) -> Ordering {
+    let (a, b) = match (a, b) {
+        (None, None) => return Ordering::Equal,
+        (None, Some(_)) => return Ordering::Greater,
+        (Some(_), None) => return Ordering::Less,
+        (Some(a), Some(b)) => (a, b),
+    };
+    let mut a = a.split('.');
+    let mut b = b.split('.');
+    loop {
+        let ordering = match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => match (x.parse::<u64>(), y.parse::<u64>()) {
+                (Ok(x), Ok(y)) => x.cmp(&y),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => x.cmp(y),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+// Semantic versions are ordered by semver precedence.
+// Build metadata has no precedence, it only breaks
+// ties to keep the ordering consistent with Eq.
+// Git commits can not be ordered meaningfully,
+// so they come first, Latest always comes last.
+impl Ord for CrateVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Latest, Self::Latest) => Ordering::Equal,
+            (Self::Latest, _) => Ordering::Greater,
+            (_, Self::Latest) => Ordering::Less,
+            (Self::GitCommit { hash: a }, Self::GitCommit { hash: b }) => a.cmp(b),
+            (Self::GitCommit { .. }, _) => Ordering::Less,
+            (_, Self::GitCommit { .. }) => Ordering::Greater,
+            (
+                Self::Semantic {
+                    major,
+                    minor,
+                    patch,
+                    suffix,
+                    build,
+                },
+                Self::Semantic {
+                    major: other_major,
+                    minor: other_minor,
+                    patch: other_patch,
+                    suffix: other_suffix,
+                    build: other_build,
+                },
+            ) => (major, minor, patch)
+                .cmp(&(other_major, other_minor, other_patch))
+                .then_with(|| compare_pre_releases(suffix, other_suffix))
+                .then_with(|| build.cmp(other_build)),
+        }
+    }
+}
+
+impl PartialOrd for CrateVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -1,41 +1,34 @@
 use scraper::{ElementRef, Node};
 use selectors::attr::CaseSensitivity;
 
-use crate::{BlockContainer, Content, Language, TextAtomic, TextAtomicBuilder};
+use crate::{
+    url::join_url, BlockContainer, CalloutKind, Content, Language, Table, TableAlignment,
+    TableCell, TextAtomic, TextStyle,
+};
 
 use super::error::HtmlParseError;
 
-/// Find and parse the main content
-/// of the documentation page,
-/// from title to bottom.
+// Find and parse the main content
+// of the documentation page,
+// from title to bottom.
 
 // RecursiveResult might contain
 // unfinished recursive results, which have
 // to be combined to a greater structure.
 enum RecursiveResult {
-    TableRows(Vec<Vec<TextAtomic>>),
+    ListItems(Vec<Vec<BlockContainer>>),
+    TableCells(Vec<TableCell>),
+    TableRows(Vec<TableRow>),
     Atomics(Vec<TextAtomic>),
     Blocks(Vec<BlockContainer>),
 }
 
 impl RecursiveResult {
     pub fn is_atomics(&self) -> bool {
-        match self {
-            Self::Atomics(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Atomics(_))
     }
     pub fn _is_blocks(&self) -> bool {
-        match self {
-            Self::Blocks(_) => true,
-            _ => false,
-        }
-    }
-    pub fn is_table_rows(&self) -> bool {
-        match self {
-            Self::TableRows(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Blocks(_))
     }
     pub fn atomics(self) -> Option<Vec<TextAtomic>> {
         match self {
@@ -49,12 +42,12 @@ impl RecursiveResult {
             _ => None,
         }
     }
-    pub fn table_rows(self) -> Option<Vec<Vec<TextAtomic>>> {
-        match self {
-            Self::TableRows(rows) => Some(rows),
-            _ => None,
-        }
-    }
+}
+
+fn is_whitespace(atomics: &[TextAtomic]) -> bool {
+    atomics
+        .iter()
+        .all(|a| !a.style.is_code() && a.text.trim().is_empty())
 }
 
 fn bundle_resursive_results_to_block_vec(
@@ -66,27 +59,59 @@ fn bundle_resursive_results_to_block_vec(
         match child {
             RecursiveResult::Atomics(mut v) => neighboring_atomics.append(&mut v),
             RecursiveResult::Blocks(mut b) => {
-                if !neighboring_atomics.is_empty() {
+                // e.g. the spaces between the images of a badge row
+                if !is_whitespace(&neighboring_atomics) {
                     result.push(BlockContainer::Paragraph(neighboring_atomics));
-                    neighboring_atomics = Vec::new();
                 }
+                neighboring_atomics = Vec::new();
                 result.append(&mut b)
             }
-            RecursiveResult::TableRows(_) => {
+            RecursiveResult::TableRows(_) | RecursiveResult::TableCells(_) => {
                 return Err(HtmlParseError::invalid_html(
                     "Table row appeared outside of a table.",
                 ));
             }
+            RecursiveResult::ListItems(_) => {
+                return Err(HtmlParseError::invalid_html(
+                    "List item appeared outside of a list.",
+                ));
+            }
         }
     }
-    if !neighboring_atomics.is_empty() {
+    if !is_whitespace(&neighboring_atomics) {
         result.push(BlockContainer::Paragraph(neighboring_atomics));
     }
     Ok(result)
 }
 
-fn get_language_of_code(_e: &ElementRef) -> Option<Language> {
-    None
+// Rustdoc marks code blocks with classes like
+// "rust", "rust-example-rendered" or "language-toml",
+// either on the <code> itself or on the surrounding <pre>.
+fn get_language_of_class(class: &str) -> Option<Language> {
+    if let Some(name) = class.strip_prefix("language-") {
+        return Language::from_str(name);
+    }
+    match class {
+        "rust" | "rust-example-rendered" | "item-decl" => Some(Language::Rust),
+        _ => None,
+    }
+}
+
+fn get_language_of_code(element: &ElementRef) -> Option<Language> {
+    let language = element.value().classes().find_map(get_language_of_class);
+    if language.is_some() {
+        return language;
+    }
+    // Do not leak the language of an outer block
+    // into an unrelated code block further down.
+    if element
+        .value()
+        .has_class("docblock", CaseSensitivity::AsciiCaseInsensitive)
+    {
+        return None;
+    }
+    let parent = element.parent().and_then(ElementRef::wrap)?;
+    get_language_of_code(&parent)
 }
 
 fn atomics_to_string(atomics: Vec<TextAtomic>) -> String {
@@ -101,7 +126,6 @@ enum RecursiveChildrenSituation {
     NoChildren,
     AllBlocks(Vec<BlockContainer>),
     AllAtomics(Vec<Vec<TextAtomic>>),
-    AllTableRows(Vec<Vec<Vec<TextAtomic>>>),
 }
 
 fn children_to_recursive_children_situation(
@@ -118,40 +142,26 @@ fn children_to_recursive_children_situation(
         return Ok(RecursiveChildrenSituation::AllAtomics(atomics));
     }
 
-    let all_table_rows = !children.iter().any(|x| !x.is_table_rows());
-    if all_table_rows {
-        let table: Vec<Vec<Vec<TextAtomic>>> = children
-            .into_iter()
-            .map(|x| x.table_rows().unwrap())
-            .collect();
-        return Ok(RecursiveChildrenSituation::AllTableRows(table));
-    }
-
     let blocks = bundle_resursive_results_to_block_vec(children)?;
     Ok(RecursiveChildrenSituation::AllBlocks(blocks))
 }
 
-fn is_inline_code(element: &ElementRef) -> bool {
-    fn is_preformatted(element: &ElementRef) -> bool {
-        if element.value().name() == "pre" {
-            return true;
-        }
-        let parent = element.parent().and_then(ElementRef::wrap);
-        if parent.is_none() {
-            return false;
-        }
-        is_preformatted(&parent.unwrap())
+fn is_preformatted(element: &ElementRef) -> bool {
+    if element.value().name() == "pre" {
+        return true;
     }
-
-    if is_preformatted(element) {
+    let parent = element.parent().and_then(ElementRef::wrap);
+    if parent.is_none() {
         return false;
     }
+    is_preformatted(&parent.unwrap())
+}
 
-    let all_children_are_flat_text = !element.children().any(|x| !x.value().is_text());
-    if all_children_are_flat_text {
-        return true;
-    }
-    false
+// Inline code may contain links,
+// like <code><a>Vec</a>&lt;T&gt;</code>,
+// so only <pre> decides about code blocks.
+fn is_inline_code(element: &ElementRef) -> bool {
+    !is_preformatted(element)
 }
 
 fn element_with_atomics_to_recursive_result(
@@ -171,11 +181,9 @@ fn element_with_atomics_to_recursive_result(
         "h4" | "h5" | "h6" => ok_some_block(BlockContainer::Heading4(flatten(atomics))),
         "code" => {
             if is_inline_code(element) {
-                Ok(Some(RecursiveResult::Atomics(vec![
-                    TextAtomicBuilder::new(&atomics_to_string(flatten(atomics)))
-                        .code(true)
-                        .build(),
-                ])))
+                // Code style has already been applied
+                // to the text atomics themselves.
+                Ok(Some(RecursiveResult::Atomics(flatten(atomics))))
             } else {
                 ok_some_block(BlockContainer::Code {
                     code: atomics_to_string(flatten(atomics)),
@@ -183,13 +191,197 @@ fn element_with_atomics_to_recursive_result(
                 })
             }
         }
-        "summary" | "button" => Ok(None),
+        // Older rustdoc versions render highlighted
+        // examples as <pre> without an inner <code>.
+        "pre" => ok_some_block(BlockContainer::Code {
+            code: atomics_to_string(flatten(atomics)),
+            language: get_language_of_code(element),
+        }),
+        "summary" => Ok(None),
         "p" => ok_some_block(BlockContainer::Paragraph(flatten(atomics))),
-        "tr" => Ok(Some(RecursiveResult::TableRows(vec![flatten(atomics)]))),
         _ => Ok(Some(RecursiveResult::Atomics(flatten(atomics)))),
     }
 }
 
+// Unlike most elements, list elements
+// have to be handled no matter if their
+// children are atomics or blocks.
+fn list_element_to_recursive_result(
+    element: &ElementRef,
+    children: Vec<RecursiveResult>,
+) -> Result<Option<RecursiveResult>, HtmlParseError> {
+    if element.value().name() == "li" {
+        let point = bundle_resursive_results_to_block_vec(children)?;
+        return Ok(Some(RecursiveResult::ListItems(vec![point])));
+    }
+
+    let mut points = Vec::new();
+    for child in children.into_iter() {
+        match child {
+            RecursiveResult::ListItems(mut p) => points.append(&mut p),
+            // Best effort for content directly inside <ul>
+            other => points.push(bundle_resursive_results_to_block_vec(vec![other])?),
+        }
+    }
+    if points.is_empty() {
+        return Ok(None);
+    }
+
+    let enumerated = element.value().name() == "ol";
+    let start = if enumerated {
+        let start = element.value().attr("start");
+        Some(start.and_then(|s| s.trim().parse().ok()).unwrap_or(1))
+    } else {
+        None
+    };
+    Ok(Some(RecursiveResult::Blocks(vec![
+        BlockContainer::BulletPoints {
+            points,
+            enumerated,
+            start,
+        },
+    ])))
+}
+
+struct TableRow {
+    header: bool,
+    cells: Vec<TableCell>,
+}
+
+// Table cells only hold inline content,
+// so blocks inside of a cell are joined.
+fn blocks_to_atomics(blocks: Vec<BlockContainer>) -> Vec<TextAtomic> {
+    let mut atomics = Vec::new();
+    for block in blocks.into_iter() {
+        if !atomics.is_empty() {
+            atomics.push(TextAtomic::simple(&" "));
+        }
+        match block {
+            BlockContainer::Heading1(mut a)
+            | BlockContainer::Heading2(mut a)
+            | BlockContainer::Heading3(mut a)
+            | BlockContainer::Heading4(mut a)
+            | BlockContainer::Paragraph(mut a) => atomics.append(&mut a),
+            BlockContainer::Code { code, .. } => atomics.push(TextAtomic {
+                text: code,
+                style: TextStyle::default().with_code(true),
+                url: None,
+            }),
+            BlockContainer::Quote(b) | BlockContainer::Callout { content: b, .. } => {
+                atomics.append(&mut blocks_to_atomics(b))
+            }
+            BlockContainer::BulletPoints { points, .. } => atomics.append(&mut blocks_to_atomics(
+                points.into_iter().flatten().collect(),
+            )),
+            BlockContainer::Table(_) | BlockContainer::Image { .. } => (),
+        }
+    }
+    atomics
+}
+
+fn get_alignment_of_cell(element: &ElementRef) -> Option<TableAlignment> {
+    let style = element.value().attr("style").unwrap_or_default();
+    let text_align = style
+        .split(';')
+        .filter_map(|declaration| declaration.split_once(':'))
+        .find(|(property, _)| property.trim().eq_ignore_ascii_case("text-align"))
+        .map(|(_, value)| value);
+    match text_align
+        .or_else(|| element.value().attr("align"))?
+        .trim()
+        .to_lowercase()
+        .as_str()
+    {
+        "left" | "start" => Some(TableAlignment::Left),
+        "center" => Some(TableAlignment::Center),
+        "right" | "end" => Some(TableAlignment::Right),
+        _ => None,
+    }
+}
+
+fn get_span_of_cell(element: &ElementRef, attribute: &str) -> u32 {
+    element
+        .value()
+        .attr(attribute)
+        .and_then(|s| s.trim().parse().ok())
+        .filter(|span| *span > 0)
+        .unwrap_or(1)
+}
+
+// Tables are built bottom up:
+// <td>/<th> turn into cells, <tr> into rows,
+// <thead>/<tbody>/<tfoot> pass their rows on
+// and <table> finally becomes a block.
+fn table_element_to_recursive_result(
+    element: &ElementRef,
+    children: Vec<RecursiveResult>,
+) -> Result<Option<RecursiveResult>, HtmlParseError> {
+    let name = element.value().name();
+    if let "th" | "td" = name {
+        let content = blocks_to_atomics(bundle_resursive_results_to_block_vec(children)?);
+        let cell = TableCell::new(content)
+            .with_header(name == "th")
+            .with_alignment(get_alignment_of_cell(element))
+            .with_span(
+                get_span_of_cell(element, "colspan"),
+                get_span_of_cell(element, "rowspan"),
+            );
+        return Ok(Some(RecursiveResult::TableCells(vec![cell])));
+    }
+
+    if name == "tr" {
+        let mut cells = Vec::new();
+        for child in children.into_iter() {
+            match child {
+                RecursiveResult::TableCells(mut c) => cells.append(&mut c),
+                // Best effort for content directly inside <tr>
+                other => cells.push(TableCell::new(blocks_to_atomics(
+                    bundle_resursive_results_to_block_vec(vec![other])?,
+                ))),
+            }
+        }
+        let header = !cells.is_empty() && cells.iter().all(|c| c.header);
+        return Ok(Some(RecursiveResult::TableRows(vec![TableRow {
+            header,
+            cells,
+        }])));
+    }
+
+    let mut rows = Vec::new();
+    for child in children.into_iter() {
+        // Anything else, e.g. <caption>, has no place in the model
+        if let RecursiveResult::TableRows(mut r) = child {
+            rows.append(&mut r);
+        }
+    }
+    if name == "thead" {
+        rows.iter_mut().for_each(|r| r.header = true);
+    }
+    if name != "table" {
+        return Ok(Some(RecursiveResult::TableRows(rows)));
+    }
+    if rows.is_empty() {
+        return Ok(None);
+    }
+
+    // Rows of <th> only are header rows
+    // as long as no body row came before them.
+    let mut table = Table {
+        header: Vec::new(),
+        body: Vec::new(),
+    };
+    for row in rows.into_iter() {
+        if row.header && table.body.is_empty() {
+            table.header.push(row.cells);
+        } else {
+            table.body.push(row.cells);
+        }
+    }
+    Ok(Some(RecursiveResult::Blocks(vec![BlockContainer::Table(
+        table,
+    )])))
+}
+
 fn get_href_walking_up_tree(element: &ElementRef) -> Option<String> {
     let href = element.value().attr("href").map(|x| x.to_string());
     if href.is_some() {
@@ -201,11 +393,139 @@ fn get_href_walking_up_tree(element: &ElementRef) -> Option<String> {
     get_href_walking_up_tree(&parent.unwrap())
 }
 
+fn get_style_of_element(element: &ElementRef) -> TextStyle {
+    let style = TextStyle::default();
+    match element.value().name() {
+        "strong" | "b" => style.with_bold(true),
+        "em" | "i" => style.with_italic(true),
+        "del" | "s" | "strike" => style.with_strike_through(true),
+        "u" | "ins" => style.with_underline(true),
+        "code" | "kbd" | "samp" | "tt" if is_inline_code(element) => style.with_code(true),
+        _ => style,
+    }
+}
+
+fn get_style_walking_up_tree(element: &ElementRef) -> TextStyle {
+    let style = get_style_of_element(element);
+    match element.parent().and_then(ElementRef::wrap) {
+        Some(parent) => style.inherit(&get_style_walking_up_tree(&parent)),
+        None => style,
+    }
+}
+
 fn is_hidden(element: &ElementRef) -> bool {
     let out_of_band = element
         .value()
         .has_class("out-of-band", CaseSensitivity::AsciiCaseInsensitive);
-    vec![out_of_band].iter().any(|x| *x)
+    // e.g. the "copy item path" button next to the title
+    let button = element.value().name() == "button";
+    // 👎 and 🔬 in front of deprecation and unstable notices
+    let emoji = element
+        .value()
+        .has_class("emoji", CaseSensitivity::AsciiCaseInsensitive);
+    [out_of_band, button, emoji].iter().any(|x| *x)
+}
+
+fn is_in_item_info(element: &ElementRef) -> bool {
+    element.ancestors().filter_map(ElementRef::wrap).any(|e| {
+        e.value()
+            .has_class("item-info", CaseSensitivity::AsciiCaseInsensitive)
+    })
+}
+
+fn get_callout_kind(element: &ElementRef) -> Option<CalloutKind> {
+    let has_class = |class: &str| {
+        element
+            .value()
+            .has_class(class, CaseSensitivity::AsciiCaseInsensitive)
+    };
+    // Inline badges like <span class="stab portability">
+    // stay part of the sentence around them.
+    let block = matches!(element.value().name(), "div" | "aside");
+    if !block && !is_in_item_info(element) {
+        return None;
+    }
+    if has_class("warning") {
+        return Some(CalloutKind::Warning);
+    }
+    if has_class("note") {
+        return Some(CalloutKind::Note);
+    }
+    // "stab" from rustdoc, "callout" from Content::to_html
+    if !has_class("stab") && !has_class("callout") {
+        return None;
+    }
+    [
+        ("deprecated", CalloutKind::Deprecated),
+        ("unstable", CalloutKind::Unstable),
+        ("portability", CalloutKind::Portability),
+        ("empty-impl", CalloutKind::EmptyImpl),
+    ]
+    .into_iter()
+    .find(|(class, _)| has_class(class))
+    .map(|(_, kind)| kind)
+}
+
+// "small.png 1x, big.png 2x" or "small.png 480w, big.png 1080w".
+// The biggest candidate wins.
+fn get_best_of_srcset(srcset: &str) -> Option<String> {
+    srcset
+        .split(',')
+        .filter_map(|candidate| {
+            let mut parts = candidate.split_whitespace();
+            let url = parts.next()?;
+            let descriptor = parts.next().unwrap_or("1x");
+            let size = descriptor
+                .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+                .parse::<f32>()
+                .unwrap_or(1.);
+            Some((url, size))
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(url, _)| url.to_string())
+}
+
+fn get_source_of_image(element: &ElementRef) -> Option<String> {
+    let img = element.value();
+    let from_picture = || {
+        let picture = element
+            .parent()
+            .and_then(ElementRef::wrap)
+            .filter(|p| p.value().name() == "picture")?;
+        picture
+            .children()
+            .filter_map(ElementRef::wrap)
+            .filter(|c| c.value().name() == "source")
+            .find_map(|c| c.value().attr("srcset").and_then(get_best_of_srcset))
+    };
+    img.attr("srcset")
+        .and_then(get_best_of_srcset)
+        .or_else(|| img.attr("src").map(|s| s.to_string()))
+        .filter(|s| !s.trim().is_empty())
+        .or_else(from_picture)
+}
+
+fn get_dimension_of_image(element: &ElementRef, attribute: &str) -> Option<u32> {
+    let value = element.value().attr(attribute)?.trim();
+    value.trim_end_matches("px").parse().ok()
+}
+
+fn image_to_recursive_result(element: &ElementRef) -> Option<RecursiveResult> {
+    let non_empty = |attribute: &str| {
+        element
+            .value()
+            .attr(attribute)
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| s.to_string())
+    };
+    Some(RecursiveResult::Blocks(vec![BlockContainer::Image {
+        url: get_source_of_image(element)?,
+        alt: non_empty("alt"),
+        title: non_empty("title"),
+        width: get_dimension_of_image(element, "width"),
+        height: get_dimension_of_image(element, "height"),
+        link: get_href_walking_up_tree(element),
+    }]))
 }
 
 fn parse_to_content_recursively(
@@ -217,13 +537,13 @@ fn parse_to_content_recursively(
     let mut children_options = Vec::new();
     for child in element.children() {
         match child.value() {
-            Node::Text(t) => children_options.push(Some(RecursiveResult::Atomics(vec![
-                // TODO: Get style by walking up the tree recursively
-                {
-                    let href = get_href_walking_up_tree(element);
-                    TextAtomic::simple(&t.to_string()).with_url(href)
-                },
-            ]))),
+            Node::Text(t) => {
+                children_options.push(Some(RecursiveResult::Atomics(vec![TextAtomic {
+                    text: t.to_string(),
+                    style: get_style_walking_up_tree(element),
+                    url: get_href_walking_up_tree(element),
+                }])))
+            }
             Node::Element(_) => {
                 children_options.push(parse_to_content_recursively(
                     &ElementRef::wrap(child).unwrap(),
@@ -233,41 +553,126 @@ fn parse_to_content_recursively(
         }
     }
 
-    fn ok_some_block(b: BlockContainer) -> Result<Option<RecursiveResult>, HtmlParseError> {
-        Ok(Some(RecursiveResult::Blocks(vec![b])))
+    let children: Vec<RecursiveResult> = children_options.into_iter().flatten().collect();
+
+    if let Some(kind) = get_callout_kind(element) {
+        let content = bundle_resursive_results_to_block_vec(children)?;
+        if content.is_empty() {
+            return Ok(None);
+        }
+        return Ok(Some(RecursiveResult::Blocks(vec![
+            BlockContainer::Callout { kind, content },
+        ])));
     }
 
-    let children: Vec<RecursiveResult> = children_options.into_iter().flatten().collect();
+    match element.value().name() {
+        "ul" | "ol" | "li" => return list_element_to_recursive_result(element, children),
+        "img" => return Ok(image_to_recursive_result(element)),
+        "blockquote" => {
+            let quoted = bundle_resursive_results_to_block_vec(children)?;
+            if quoted.is_empty() {
+                return Ok(None);
+            }
+            return Ok(Some(RecursiveResult::Blocks(vec![BlockContainer::Quote(
+                quoted,
+            )])));
+        }
+        "table" | "thead" | "tbody" | "tfoot" | "tr" | "th" | "td" => {
+            return table_element_to_recursive_result(element, children)
+        }
+        _ => (),
+    }
 
     let situation = children_to_recursive_children_situation(children)?;
     match situation {
         RecursiveChildrenSituation::NoChildren => Ok(None),
         RecursiveChildrenSituation::AllBlocks(blocks) => Ok(Some(RecursiveResult::Blocks(blocks))),
-        RecursiveChildrenSituation::AllTableRows(table) => {
-            if element.value().name() != "table" {
-                Err(HtmlParseError::invalid_html(format!(
-                    "Table rows in <{}>. Expected <table>.",
-                    element.value().name()
-                )))
-            } else {
-                ok_some_block(BlockContainer::Table(table))
-            }
-        }
         RecursiveChildrenSituation::AllAtomics(atomics) => {
             Ok(element_with_atomics_to_recursive_result(element, atomics)?)
         }
     }
 }
 
+// Calls f on every block, including
+// the ones nested in quotes, lists and callouts.
+fn for_each_block_mut<F>(blocks: &mut [BlockContainer], f: &mut F)
+where
+    F: FnMut(&mut BlockContainer),
+{
+    for block in blocks.iter_mut() {
+        f(block);
+        match block {
+            BlockContainer::Quote(b) | BlockContainer::Callout { content: b, .. } => {
+                for_each_block_mut(b, f)
+            }
+            BlockContainer::BulletPoints { points, .. } => {
+                points.iter_mut().for_each(|p| for_each_block_mut(p, f))
+            }
+            _ => (),
+        }
+    }
+}
+
+// Image sources are kept as written in the HTML
+// until we know where the page lives.
+pub fn resolve_image_sources(blocks: &mut [BlockContainer], base: &str) {
+    for_each_block_mut(blocks, &mut |block| {
+        if let BlockContainer::Image { url, .. } = block {
+            *url = join_url(base, url);
+        }
+    });
+}
+
+// Same for links, but only on request,
+// as relative links are shorter to display.
+pub fn resolve_links(blocks: &mut [BlockContainer], base: &str) {
+    let resolve = |atomics: &mut Vec<TextAtomic>| {
+        for atomic in atomics.iter_mut() {
+            if let Some(url) = &atomic.url {
+                atomic.url = Some(join_url(base, url));
+            }
+        }
+    };
+    for_each_block_mut(blocks, &mut |block| match block {
+        BlockContainer::Image {
+            link: Some(link), ..
+        } => *link = join_url(base, link),
+        BlockContainer::Heading1(a)
+        | BlockContainer::Heading2(a)
+        | BlockContainer::Heading3(a)
+        | BlockContainer::Heading4(a)
+        | BlockContainer::Paragraph(a) => resolve(a),
+        BlockContainer::Table(table) => table
+            .header
+            .iter_mut()
+            .chain(table.body.iter_mut())
+            .flatten()
+            .for_each(|cell| resolve(&mut cell.content)),
+        _ => (),
+    });
+}
+
 pub fn parse_to_content(element: &ElementRef) -> Result<Content, HtmlParseError> {
     match parse_to_content_recursively(element)? {
         Some(RecursiveResult::Atomics(atomics)) => {
             Ok(Content(vec![BlockContainer::Paragraph(atomics)]))
         }
         Some(RecursiveResult::Blocks(b)) => Ok(Content(b)),
-        Some(RecursiveResult::TableRows(_)) => todo!(),
+        Some(RecursiveResult::TableRows(_)) | Some(RecursiveResult::TableCells(_)) => Err(
+            HtmlParseError::invalid_html("Table row appeared outside of a table."),
+        ),
+        Some(RecursiveResult::ListItems(_)) => Err(HtmlParseError::invalid_html(
+            "List item appeared outside of a list.",
+        )),
         None => Err(HtmlParseError::InvalidHtml(
             "HTML Element contained no content.".to_string(),
         )),
     }
 }
+
+// Short descriptions like those in item tables.
+pub fn parse_to_atomics(element: &ElementRef) -> Vec<TextAtomic> {
+    parse_to_content(element)
+        .map(|content| blocks_to_atomics(content.0))
+        .unwrap_or_default()
+}
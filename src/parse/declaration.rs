@@ -0,0 +1,853 @@
+use scraper::{ElementRef, Node, Selector};
+
+use crate::{
+    Argument, Content, DeclarationKind, Field, Fields, FieldsStyle, FunctionSignature,
+    GenericParam, GenericParamKind, ItemDeclaration, TextAtomic, TraitItem, Variant,
+    WherePredicate,
+};
+
+use super::content::parse_to_content;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum TokenKind {
+    Word, // identifiers, keywords and numbers
+    Lifetime,
+    Literal, // "C", 'a'
+    Punct,
+    Comment,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Token {
+    pub text: String,
+    pub kind: TokenKind,
+    pub url: Option<String>,
+    pub space_before: bool,
+}
+
+// Text of the element together with the link it is part of.
+// <br> is kept as newline so line comments end there.
+fn collect_segments(
+    element: &ElementRef,
+    url: Option<&str>,
+    segments: &mut Vec<(String, Option<String>)>,
+) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => segments.push((text.to_string(), url.map(String::from))),
+            Node::Element(e) if e.name() == "br" => segments.push(("\n".to_string(), None)),
+            Node::Element(e) => {
+                let url = e.attr("href").or(url);
+                collect_segments(&ElementRef::wrap(child).unwrap(), url, segments);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+pub(super) fn tokenize(element: &ElementRef) -> Vec<Token> {
+    let mut segments = vec![];
+    collect_segments(element, None, &mut segments);
+    let chars = segments
+        .iter()
+        .enumerate()
+        .flat_map(|(i, (text, _))| text.chars().map(move |c| (c, i)))
+        .collect::<Vec<(char, usize)>>();
+    let at = |i: usize| chars.get(i).map(|(c, _)| *c);
+
+    let mut tokens = vec![];
+    // Open brackets, to tell generic lists from shifts
+    let mut open = vec![];
+    let mut space_before = false;
+    let mut i = 0;
+    while let Some(c) = at(i) {
+        if c.is_whitespace() {
+            space_before = true;
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let kind = match (c, at(i + 1)) {
+            ('/', Some('/')) => {
+                while at(i).is_some_and(|c| c != '\n') {
+                    i += 1;
+                }
+                TokenKind::Comment
+            }
+            ('/', Some('*')) => {
+                i += 2;
+                while at(i).is_some() && !(at(i) == Some('*') && at(i + 1) == Some('/')) {
+                    i += 1;
+                }
+                i = (i + 2).min(chars.len());
+                TokenKind::Comment
+            }
+            ('"', _) => {
+                i += 1;
+                while let Some(c) = at(i) {
+                    i += if c == '\\' { 2 } else { 1 };
+                    if c == '"' {
+                        break;
+                    }
+                }
+                i = i.min(chars.len());
+                TokenKind::Literal
+            }
+            ('\'', Some('\\')) => {
+                i += 3;
+                while at(i).is_some_and(|c| c != '\'') {
+                    i += 1;
+                }
+                i = (i + 1).min(chars.len());
+                TokenKind::Literal
+            }
+            ('\'', Some(_)) if at(i + 2) == Some('\'') => {
+                i += 3;
+                TokenKind::Literal
+            }
+            ('\'', _) => {
+                i += 1;
+                while at(i).is_some_and(is_word_char) {
+                    i += 1;
+                }
+                TokenKind::Lifetime
+            }
+            (c, _) if is_word_char(c) => {
+                while at(i).is_some_and(is_word_char) {
+                    i += 1;
+                }
+                TokenKind::Word
+            }
+            // ">>" closing two generic lists stays two tokens,
+            // elsewhere it is a shift, e.g. in "[u8; { 1 >> 2 }]".
+            ('>', Some('>')) if open.last() != Some(&'<') => {
+                i += 2;
+                TokenKind::Punct
+            }
+            ('.', Some('.')) => {
+                i += if at(i + 2) == Some('.') { 3 } else { 2 };
+                TokenKind::Punct
+            }
+            (':', Some(':')) | ('-', Some('>')) | ('=', Some('>')) => {
+                i += 2;
+                TokenKind::Punct
+            }
+            _ => {
+                i += 1;
+                TokenKind::Punct
+            }
+        };
+        let token = Token {
+            text: chars[start..i].iter().map(|(c, _)| c).collect(),
+            kind,
+            url: segments[chars[start].1].1.clone(),
+            space_before,
+        };
+        if is_opening(&token) {
+            open.push(c);
+        } else if is_closing(&token) {
+            open.pop();
+        }
+        tokens.push(token);
+        space_before = false;
+    }
+    tokens
+}
+
+pub(super) fn tokens_to_string(tokens: &[Token]) -> String {
+    let mut text = String::new();
+    for (i, token) in tokens.iter().enumerate() {
+        if i > 0 && token.space_before {
+            text.push(' ');
+        }
+        text.push_str(&token.text);
+    }
+    text
+}
+
+// Neighbouring tokens with the same link are merged,
+// spaces in front of links go to the plain text before them.
+pub(super) fn tokens_to_atomics(tokens: &[Token]) -> Vec<TextAtomic> {
+    let mut atomics: Vec<TextAtomic> = vec![];
+    for (i, token) in tokens.iter().enumerate() {
+        if i > 0 && token.space_before {
+            match atomics.last_mut() {
+                Some(last) if last.url.is_none() || last.url == token.url => last.text.push(' '),
+                _ => atomics.push(TextAtomic::simple(&" ")),
+            }
+        }
+        match atomics.last_mut() {
+            Some(last) if last.url == token.url => last.text.push_str(&token.text),
+            _ => atomics.push(TextAtomic::simple(&token.text).with_url(token.url.clone())),
+        }
+    }
+    atomics
+}
+
+fn is_opening(token: &Token) -> bool {
+    token.kind == TokenKind::Punct && matches!(token.text.as_str(), "(" | "[" | "{" | "<")
+}
+
+fn is_closing(token: &Token) -> bool {
+    token.kind == TokenKind::Punct && matches!(token.text.as_str(), ")" | "]" | "}" | ">")
+}
+
+// Splits at the first `separator` outside of any brackets.
+pub(super) fn split_at_top_level<'a>(
+    tokens: &'a [Token],
+    separator: &str,
+) -> (&'a [Token], Option<&'a [Token]>) {
+    let mut depth = 0usize;
+    for (i, token) in tokens.iter().enumerate() {
+        if depth == 0 && token.kind != TokenKind::Literal && token.text == separator {
+            return (&tokens[..i], Some(&tokens[i + 1..]));
+        }
+        if is_opening(token) {
+            depth += 1;
+        } else if is_closing(token) {
+            depth = depth.saturating_sub(1);
+        }
+    }
+    (tokens, None)
+}
+
+pub(super) struct TokenParser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl TokenParser {
+    pub fn new(tokens: Vec<Token>) -> Self {
+        Self {
+            tokens,
+            position: 0,
+        }
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<&Token> {
+        self.tokens.get(self.position + n)
+    }
+
+    pub fn peek_is(&self, text: &str) -> bool {
+        self.peek_nth_is(0, text)
+    }
+
+    fn peek_nth_is(&self, n: usize, text: &str) -> bool {
+        self.peek_nth(n)
+            .is_some_and(|t| t.kind != TokenKind::Literal && t.text == text)
+    }
+
+    pub fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    pub fn eat(&mut self, text: &str) -> bool {
+        let found = self.peek_is(text);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn next_word(&mut self) -> String {
+        match self.peek_nth(0) {
+            Some(t) if t.kind == TokenKind::Word => self.next().unwrap().text,
+            _ => String::new(),
+        }
+    }
+
+    pub fn rest(&mut self) -> Vec<Token> {
+        let rest = self.tokens[self.position.min(self.tokens.len())..].to_vec();
+        self.position = self.tokens.len();
+        rest
+    }
+
+    pub fn skip_comments(&mut self) -> Vec<String> {
+        let mut comments = vec![];
+        while let Some(token) = self.peek_nth(0).filter(|t| t.kind == TokenKind::Comment) {
+            comments.push(token.text.clone());
+            self.position += 1;
+        }
+        comments
+    }
+
+    // Tokens up to one of the `stops` outside of any brackets,
+    // or up to the bracket closing the one we are in.
+    pub fn take_until(&mut self, stops: &[&str]) -> Vec<Token> {
+        let mut depth = 0usize;
+        let mut taken = vec![];
+        while let Some(token) = self.peek_nth(0) {
+            if depth == 0
+                && token.kind != TokenKind::Literal
+                && stops.contains(&token.text.as_str())
+            {
+                break;
+            }
+            if is_opening(token) {
+                depth += 1;
+            } else if is_closing(token) {
+                if depth == 0 {
+                    break;
+                }
+                depth -= 1;
+            }
+            taken.push(self.next().unwrap());
+        }
+        taken
+    }
+
+    // Expressions like `1 << 4` have no balanced brackets,
+    // so values are taken up to the stop without counting them.
+    fn take_value(&mut self, stops: &[&str]) -> String {
+        let mut taken = vec![];
+        while !self.is_at_end() && !stops.iter().any(|s| self.peek_is(s)) {
+            taken.push(self.next().unwrap());
+        }
+        tokens_to_string(&taken)
+    }
+
+    fn take_balanced(&mut self) -> Vec<Token> {
+        let mut depth = 0usize;
+        let mut taken = vec![];
+        while let Some(token) = self.next() {
+            if is_opening(&token) {
+                depth += 1;
+            } else if is_closing(&token) {
+                depth = depth.saturating_sub(1);
+            }
+            taken.push(token);
+            if depth == 0 {
+                break;
+            }
+        }
+        taken
+    }
+
+    pub fn parse_attributes(&mut self) -> Vec<String> {
+        let mut attributes = vec![];
+        while self.peek_is("#") && self.peek_nth_is(1, "[") {
+            let mut tokens = vec![self.next().unwrap()];
+            tokens.extend(self.take_balanced());
+            attributes.push(tokens_to_string(&tokens));
+        }
+        attributes
+    }
+
+    // pub, pub(crate), pub(in crate::a)
+    pub fn parse_visibility(&mut self) -> Option<String> {
+        if !self.peek_is("pub") {
+            return None;
+        }
+        let mut tokens = vec![self.next().unwrap()];
+        if self.peek_is("(")
+            && ["crate", "super", "self", "in"]
+                .iter()
+                .any(|scope| self.peek_nth_is(1, scope))
+        {
+            tokens.extend(self.take_balanced());
+        }
+        Some(tokens_to_string(&tokens))
+    }
+
+    // default, const, async, unsafe, extern "C", auto
+    pub fn parse_qualifiers(&mut self) -> Vec<String> {
+        let mut qualifiers = vec![];
+        loop {
+            let is_qualifier = match self.peek_nth(0) {
+                Some(t) if t.kind != TokenKind::Word => false,
+                // `const fn`, but not `const N: usize`
+                Some(t) if t.text == "const" => ["fn", "async", "unsafe", "extern"]
+                    .iter()
+                    .any(|k| self.peek_nth_is(1, k)),
+                Some(t) => matches!(
+                    t.text.as_str(),
+                    "default" | "async" | "unsafe" | "extern" | "auto"
+                ),
+                None => false,
+            };
+            if !is_qualifier {
+                return qualifiers;
+            }
+            let mut qualifier = self.next().unwrap().text;
+            if qualifier == "extern" {
+                if let Some(abi) = self.peek_nth(0).filter(|t| t.kind == TokenKind::Literal) {
+                    qualifier = format!("extern {}", abi.text);
+                    self.position += 1;
+                }
+            }
+            qualifiers.push(qualifier);
+        }
+    }
+
+    pub fn parse_generic_params(&mut self) -> Vec<GenericParam> {
+        let mut params = vec![];
+        if !self.eat("<") {
+            return params;
+        }
+        while !self.is_at_end() && !self.eat(">") {
+            let tokens = self.take_until(&[","]);
+            let comma = self.eat(",");
+            let Some(first) = tokens.first() else {
+                // Skips a stray closing bracket.
+                if !comma {
+                    self.next();
+                }
+                continue;
+            };
+            let (kind, declaration) = if first.kind == TokenKind::Lifetime {
+                (GenericParamKind::Lifetime, &tokens[..])
+            } else if first.text == "const" {
+                (GenericParamKind::Const, &tokens[1..])
+            } else {
+                (GenericParamKind::Type, &tokens[..])
+            };
+            let (declaration, default) = split_at_top_level(declaration, "=");
+            let (name, bounds) = split_at_top_level(declaration, ":");
+            params.push(GenericParam {
+                name: tokens_to_string(name),
+                kind,
+                bounds: tokens_to_atomics(bounds.unwrap_or_default()),
+                default: default.map(tokens_to_atomics),
+            });
+        }
+        params
+    }
+
+    pub fn parse_where_clause(&mut self) -> Vec<WherePredicate> {
+        let mut predicates = vec![];
+        if !self.eat("where") {
+            return predicates;
+        }
+        loop {
+            let tokens = self.take_until(&[",", "{", ";", "="]);
+            if !tokens.is_empty() {
+                let (bounded, bounds) = split_at_top_level(&tokens, ":");
+                predicates.push(WherePredicate {
+                    bounded: tokens_to_atomics(bounded),
+                    bounds: tokens_to_atomics(bounds.unwrap_or_default()),
+                });
+            }
+            if !self.eat(",") {
+                return predicates;
+            }
+        }
+    }
+
+    // Everything after the `fn` keyword.
+    pub fn parse_function_signature(&mut self, qualifiers: Vec<String>) -> FunctionSignature {
+        let name = self.next_word();
+        let generics = self.parse_generic_params();
+        let mut arguments = vec![];
+        if self.eat("(") {
+            while !self.is_at_end() && !self.eat(")") {
+                let tokens = self.take_until(&[","]);
+                let comma = self.eat(",");
+                if tokens.is_empty() {
+                    // Skips a stray closing bracket.
+                    if !comma {
+                        self.next();
+                    }
+                    continue;
+                }
+                arguments.push(match split_at_top_level(&tokens, ":") {
+                    (mut name, Some(ty)) => {
+                        // "mut x: T" and "ref x: T" are named "x"
+                        while name.len() > 1 && matches!(name[0].text.as_str(), "mut" | "ref") {
+                            name = &name[1..];
+                        }
+                        Argument {
+                            name: tokens_to_string(name),
+                            ty: tokens_to_atomics(ty),
+                        }
+                    }
+                    (receiver, None) => Argument {
+                        name: "self".to_string(),
+                        ty: tokens_to_atomics(receiver),
+                    },
+                });
+            }
+        }
+        let return_type = if self.eat("->") {
+            Some(tokens_to_atomics(&self.take_until(&["where", "{", ";"])))
+        } else {
+            None
+        };
+        FunctionSignature {
+            qualifiers,
+            name,
+            generics,
+            arguments,
+            return_type,
+            where_clause: self.parse_where_clause(),
+        }
+    }
+
+    // { a: u32, pub b: T, /* private fields */ }
+    fn parse_named_fields(&mut self) -> Fields {
+        let mut fields = Fields {
+            style: FieldsStyle::Named,
+            fields: vec![],
+            has_private_fields: false,
+        };
+        self.eat("{");
+        loop {
+            if self.skip_comments().iter().any(|c| is_hidden_marker(c)) {
+                fields.has_private_fields = true;
+            }
+            if self.is_at_end() || self.eat("}") {
+                return fields;
+            }
+            self.parse_attributes();
+            let visibility = self.parse_visibility();
+            let name = self.next_word();
+            self.eat(":");
+            let ty = self.take_until(&[","]);
+            if !self.eat(",") && name.is_empty() && ty.is_empty() {
+                self.next();
+                continue;
+            }
+            fields.fields.push(Field {
+                visibility,
+                name,
+                ty: tokens_to_atomics(&ty),
+                docs: None,
+            });
+        }
+    }
+
+    // (pub u32, _), where `_` stands for a private field
+    fn parse_tuple_fields(&mut self) -> Fields {
+        let mut fields = Fields {
+            style: FieldsStyle::Tuple,
+            fields: vec![],
+            has_private_fields: false,
+        };
+        self.eat("(");
+        let mut index = 0;
+        loop {
+            if self.skip_comments().iter().any(|c| is_hidden_marker(c)) {
+                fields.has_private_fields = true;
+            }
+            if self.is_at_end() || self.eat(")") {
+                return fields;
+            }
+            self.parse_attributes();
+            let visibility = self.parse_visibility();
+            let ty = self.take_until(&[","]);
+            if !self.eat(",") && ty.is_empty() {
+                self.next();
+                continue;
+            }
+            if tokens_to_string(&ty) == "_" {
+                fields.has_private_fields = true;
+            } else {
+                fields.fields.push(Field {
+                    visibility,
+                    name: index.to_string(),
+                    ty: tokens_to_atomics(&ty),
+                    docs: None,
+                });
+            }
+            index += 1;
+        }
+    }
+
+    fn parse_variant_fields(&mut self) -> Fields {
+        if self.peek_is("(") {
+            self.parse_tuple_fields()
+        } else if self.peek_is("{") {
+            self.parse_named_fields()
+        } else {
+            Fields {
+                style: FieldsStyle::Unit,
+                fields: vec![],
+                has_private_fields: false,
+            }
+        }
+    }
+
+    // Struct and union bodies, including the where clause
+    // which comes before `{` but after `(...)`.
+    fn parse_struct_body(&mut self) -> (Fields, Vec<WherePredicate>) {
+        let mut where_clause = self.parse_where_clause();
+        let fields = self.parse_variant_fields();
+        if fields.style == FieldsStyle::Tuple {
+            where_clause.extend(self.parse_where_clause());
+        }
+        self.eat(";");
+        (fields, where_clause)
+    }
+
+    fn parse_enum_body(&mut self) -> DeclarationKind {
+        let mut variants = vec![];
+        let mut has_hidden_variants = false;
+        self.eat("{");
+        loop {
+            if self.skip_comments().iter().any(|c| is_hidden_marker(c)) {
+                has_hidden_variants = true;
+            }
+            if self.is_at_end() || self.eat("}") {
+                break;
+            }
+            self.parse_attributes();
+            let name = self.next_word();
+            if name.is_empty() {
+                self.next();
+                continue;
+            }
+            let fields = self.parse_variant_fields();
+            let discriminant = if self.eat("=") {
+                Some(self.take_value(&[",", "}"]))
+            } else {
+                None
+            };
+            self.eat(",");
+            variants.push(Variant {
+                name,
+                fields,
+                discriminant,
+                docs: None,
+            });
+        }
+        DeclarationKind::Enum {
+            variants,
+            has_hidden_variants,
+        }
+    }
+
+    fn parse_trait_items(&mut self) -> Vec<TraitItem> {
+        let mut items = vec![];
+        self.eat("{");
+        loop {
+            self.skip_comments();
+            if self.is_at_end() || self.eat("}") {
+                return items;
+            }
+            self.parse_attributes();
+            self.parse_visibility();
+            if self.eat("type") {
+                let name = self.next_word();
+                self.parse_generic_params();
+                let bounds = if self.eat(":") {
+                    self.take_until(&["where", "=", ";"])
+                } else {
+                    vec![]
+                };
+                self.parse_where_clause();
+                let default = if self.eat("=") {
+                    Some(tokens_to_atomics(&self.take_until(&[";"])))
+                } else {
+                    None
+                };
+                self.eat(";");
+                items.push(TraitItem::AssociatedType {
+                    name,
+                    bounds: tokens_to_atomics(&bounds),
+                    default,
+                });
+                continue;
+            }
+            let qualifiers = self.parse_qualifiers();
+            if self.eat("fn") {
+                let signature = self.parse_function_signature(qualifiers);
+                let provided = self.peek_is("{");
+                if provided {
+                    self.take_balanced();
+                }
+                self.eat(";");
+                items.push(TraitItem::Method {
+                    signature,
+                    provided,
+                });
+            } else if self.eat("const") {
+                let name = self.next_word();
+                self.eat(":");
+                let ty = self.take_until(&["=", ";"]);
+                let default = if self.eat("=") {
+                    Some(self.take_value(&[";"]))
+                } else {
+                    None
+                };
+                self.eat(";");
+                items.push(TraitItem::AssociatedConstant {
+                    name,
+                    ty: tokens_to_atomics(&ty),
+                    default,
+                });
+            } else {
+                // Skips whatever this is up to the next item,
+                // or a stray closing bracket.
+                let skipped = self.take_until(&[";"]);
+                if !self.eat(";") && skipped.is_empty() {
+                    self.next();
+                }
+            }
+        }
+    }
+
+    fn parse_declaration(&mut self) -> Option<ItemDeclaration> {
+        let attributes = self.parse_attributes();
+        let visibility = self.parse_visibility();
+        let qualifiers = self.parse_qualifiers();
+        let keyword = self.next()?;
+        let mut name = self.next_word();
+        let mut generics = vec![];
+        let mut where_clause = vec![];
+        let kind = match keyword.text.as_str() {
+            "struct" | "union" => {
+                generics = self.parse_generic_params();
+                let (fields, predicates) = self.parse_struct_body();
+                where_clause = predicates;
+                if keyword.text == "struct" {
+                    DeclarationKind::Struct(fields)
+                } else {
+                    DeclarationKind::Union(fields)
+                }
+            }
+            "enum" => {
+                generics = self.parse_generic_params();
+                where_clause = self.parse_where_clause();
+                self.parse_enum_body()
+            }
+            "trait" => {
+                generics = self.parse_generic_params();
+                let supertraits = if self.eat(":") {
+                    self.take_until(&["where", "{", ";"])
+                } else {
+                    vec![]
+                };
+                where_clause = self.parse_where_clause();
+                DeclarationKind::Trait {
+                    qualifiers,
+                    supertraits: tokens_to_atomics(&supertraits),
+                    items: self.parse_trait_items(),
+                }
+            }
+            "fn" => {
+                // The name is part of the signature.
+                if !name.is_empty() {
+                    self.position -= 1;
+                }
+                let signature = self.parse_function_signature(qualifiers);
+                generics = signature.generics.clone();
+                where_clause = signature.where_clause.clone();
+                DeclarationKind::Function(signature)
+            }
+            "type" => {
+                generics = self.parse_generic_params();
+                where_clause = self.parse_where_clause();
+                let ty = if self.eat("=") {
+                    Some(tokens_to_atomics(&self.take_until(&[";", "where"])))
+                } else {
+                    None
+                };
+                where_clause.extend(self.parse_where_clause());
+                DeclarationKind::TypeAlias { ty }
+            }
+            "const" | "static" => {
+                let mutable = name == "mut";
+                if mutable {
+                    name = self.next_word();
+                }
+                self.eat(":");
+                let ty = tokens_to_atomics(&self.take_until(&["=", ";"]));
+                let value = if self.eat("=") {
+                    Some(self.take_value(&[";"]))
+                } else {
+                    None
+                };
+                if keyword.text == "const" {
+                    DeclarationKind::Constant { ty, value }
+                } else {
+                    DeclarationKind::Static { mutable, ty, value }
+                }
+            }
+            // macro_rules! name { ... }
+            _ => {
+                self.position = 0;
+                let declaration = tokens_to_string(&self.rest());
+                if name.is_empty() {
+                    if let Some((_, after)) = declaration.split_once('!') {
+                        name = after
+                            .split(|c: char| !is_word_char(c))
+                            .find(|w| !w.is_empty())?
+                            .to_string();
+                    }
+                }
+                DeclarationKind::Other(declaration)
+            }
+        };
+        Some(ItemDeclaration {
+            attributes,
+            visibility,
+            name,
+            generics,
+            where_clause,
+            kind,
+        })
+    }
+}
+
+// "/* private fields */", "/* fields omitted */", "// some variants omitted"
+fn is_hidden_marker(comment: &str) -> bool {
+    comment.contains("private") || comment.contains("omitted") || comment.contains("hidden")
+}
+
+// The docs of a field or variant are in the first docblock
+// following the element carrying its id, e.g.
+// <span id="structfield.x">...</span><div class="docblock">...</div>
+pub(super) fn get_docs_after(anchor: &ElementRef) -> Option<Content> {
+    anchor
+        .next_siblings()
+        .filter_map(ElementRef::wrap)
+        .take_while(|e| e.value().id().is_none())
+        .find(|e| e.value().classes().any(|c| c == "docblock"))
+        .and_then(|docblock| parse_to_content(&docblock).ok())
+}
+
+fn get_docs_by_id(root: &ElementRef, id: &str) -> Option<Content> {
+    let selector = Selector::parse(&format!("[id=\"{}\"]", id)).ok()?;
+    get_docs_after(&root.select(&selector).next()?)
+}
+
+fn attach_field_docs(root: &ElementRef, fields: &mut Fields, prefix: &str) {
+    for field in fields.fields.iter_mut() {
+        field.docs = get_docs_by_id(root, &format!("{}{}", prefix, field.name));
+    }
+}
+
+fn attach_docs(root: &ElementRef, declaration: &mut ItemDeclaration) {
+    match &mut declaration.kind {
+        DeclarationKind::Struct(fields) | DeclarationKind::Union(fields) => {
+            attach_field_docs(root, fields, "structfield.")
+        }
+        DeclarationKind::Enum { variants, .. } => {
+            for variant in variants.iter_mut() {
+                variant.docs = get_docs_by_id(root, &format!("variant.{}", variant.name));
+                let prefix = format!("variant.{}.field.", variant.name);
+                attach_field_docs(root, &mut variant.fields, &prefix);
+            }
+        }
+        _ => {}
+    }
+}
+
+// <div class="docblock item-decl"><pre class="rust struct"><code>pub struct ...
+// Newer rustdoc versions put the class on the <pre> itself.
+pub fn parse_item_declaration(root: &ElementRef) -> Option<ItemDeclaration> {
+    let selector = Selector::parse(".item-decl").unwrap();
+    let element = root.select(&selector).next()?;
+    let mut declaration = TokenParser::new(tokenize(&element)).parse_declaration()?;
+    attach_docs(root, &mut declaration);
+    Some(declaration)
+}
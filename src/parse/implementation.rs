@@ -0,0 +1,228 @@
+use scraper::{ElementRef, Selector};
+
+use crate::{AssociatedItem, AssociatedItemKind, ImplKind, Implementation, Stability};
+
+use super::declaration::{
+    get_docs_after, split_at_top_level, tokenize, tokens_to_atomics, TokenParser,
+};
+
+// Tokens of the code header of an impl or item,
+// without the "source" link and the anchor next to it.
+fn get_code_header<'a>(element: &ElementRef<'a>) -> ElementRef<'a> {
+    let selector = Selector::parse(".code-header, code").unwrap();
+    element.select(&selector).next().unwrap_or(*element)
+}
+
+fn get_source_link(element: &ElementRef) -> Option<String> {
+    let selector = Selector::parse("a.srclink, a.src").unwrap();
+    element
+        .select(&selector)
+        .next()
+        .and_then(|a| a.value().attr("href"))
+        .map(|href| href.to_string())
+}
+
+// The list an impl is in tells which kind it is.
+// Older pages put inherent impls right after h2#implementations.
+fn get_kind_of_list(element: &ElementRef) -> Option<ImplKind> {
+    element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .find_map(|e| match e.value().id()? {
+            "implementations-list" => Some(ImplKind::Inherent),
+            "trait-implementations-list" => Some(ImplKind::Trait),
+            "synthetic-implementations-list" => Some(ImplKind::Auto),
+            "blanket-implementations-list" => Some(ImplKind::Blanket),
+            _ => None,
+        })
+}
+
+// "method.fmt-1" -> (Method, "fmt")
+fn get_kind_and_name_of_id(id: &str) -> Option<(AssociatedItemKind, String)> {
+    let (prefix, name) = id.split_once('.')?;
+    let kind = match prefix {
+        "method" | "tymethod" => AssociatedItemKind::Method,
+        "associatedconstant" => AssociatedItemKind::Constant,
+        "associatedtype" => AssociatedItemKind::Type,
+        _ => return None,
+    };
+    Some((kind, name.split('-').next().unwrap_or_default().to_string()))
+}
+
+// Items in a <details> have their docs next to the <summary>.
+fn get_anchor_of_item<'a>(element: &ElementRef<'a>) -> ElementRef<'a> {
+    element
+        .parent()
+        .and_then(ElementRef::wrap)
+        .filter(|p| p.value().name() == "summary")
+        .unwrap_or(*element)
+}
+
+fn text_without_emoji(element: &ElementRef) -> String {
+    let emoji_selector = Selector::parse(".emoji").unwrap();
+    let emojis = element
+        .select(&emoji_selector)
+        .flat_map(|e| e.text())
+        .collect::<String>();
+    element
+        .text()
+        .collect::<String>()
+        .replacen(&emojis, "", 1)
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+// <span class="since" title="Stable since Rust version 1.0.0">1.0.0</span>
+// <div class="item-info"><div class="stab deprecated">👎 Deprecated since 1.42.0: ...</div></div>
+fn get_stability(element: &ElementRef) -> Stability {
+    let since_selector = Selector::parse(".since").unwrap();
+    let stab_selector = Selector::parse(".stab").unwrap();
+    let mut stability = Stability {
+        stable_since: element
+            .select(&since_selector)
+            .filter_map(|e| e.value().attr("title"))
+            .find_map(|title| title.strip_prefix("Stable since Rust version "))
+            .map(|version| version.split(',').next().unwrap_or_default().to_string()),
+        ..Default::default()
+    };
+    let item_info = get_anchor_of_item(element)
+        .next_siblings()
+        .filter_map(ElementRef::wrap)
+        .take_while(|e| e.value().id().is_none())
+        .find(|e| e.value().classes().any(|c| c == "item-info"));
+    for stab in item_info.iter().flat_map(|i| i.select(&stab_selector)) {
+        let text = Some(text_without_emoji(&stab));
+        for class in stab.value().classes() {
+            match class {
+                "deprecated" => stability.deprecated = text.clone(),
+                "unstable" => stability.unstable = text.clone(),
+                "portability" => stability.portability = text.clone(),
+                _ => {}
+            }
+        }
+    }
+    stability
+}
+
+fn get_associated_items(container: &ElementRef) -> Vec<AssociatedItem> {
+    let selector = Selector::parse("[id]").unwrap();
+    container
+        .select(&selector)
+        .filter_map(|element| {
+            let id = element.value().id()?;
+            let (kind, name) = get_kind_and_name_of_id(id)?;
+            Some(AssociatedItem {
+                id: id.to_string(),
+                kind,
+                name,
+                signature: tokens_to_atomics(&tokenize(&get_code_header(&element))),
+                docs: get_docs_after(&get_anchor_of_item(&element)),
+                stability: get_stability(&element),
+                source: get_source_link(&element),
+            })
+        })
+        .collect()
+}
+
+// <details class="implementors-toggle"><summary><section class="impl">...</section></summary>
+// <div class="impl-items">...</div></details>
+// Impls without items come without the <details> around them.
+fn get_impl_items<'a>(element: &ElementRef<'a>) -> Option<ElementRef<'a>> {
+    let is_impl_items = |e: &ElementRef| e.value().classes().any(|c| c == "impl-items");
+    let summary = element
+        .parent()
+        .and_then(ElementRef::wrap)
+        .filter(|p| p.value().name() == "summary");
+    summary
+        .unwrap_or(*element)
+        .next_siblings()
+        .filter_map(ElementRef::wrap)
+        .next()
+        .filter(is_impl_items)
+}
+
+// impl<T> From<T> for T where ...
+fn parse_implementation(element: &ElementRef) -> Option<Implementation> {
+    let mut parser = TokenParser::new(tokenize(&get_code_header(element)));
+    parser.parse_qualifiers();
+    if !parser.eat("impl") {
+        return None;
+    }
+    let generics = parser.parse_generic_params();
+    let header = parser.take_until(&["where"]);
+    let where_clause = parser.parse_where_clause();
+
+    let (mut implemented_trait, self_type) = match split_at_top_level(&header, "for") {
+        (implemented_trait, Some(self_type)) => (Some(implemented_trait), self_type),
+        (self_type, None) => (None, self_type),
+    };
+    let negative = implemented_trait.is_some_and(|t| t.first().is_some_and(|t| t.text == "!"));
+    if negative {
+        implemented_trait = implemented_trait.map(|t| &t[1..]);
+    }
+    let kind = get_kind_of_list(element).unwrap_or(if implemented_trait.is_some() {
+        ImplKind::Trait
+    } else {
+        ImplKind::Inherent
+    });
+
+    Some(Implementation {
+        id: element.value().id().map(|id| id.to_string()),
+        kind,
+        negative,
+        generics,
+        implemented_trait: implemented_trait.map(tokens_to_atomics),
+        self_type: tokens_to_atomics(self_type),
+        where_clause,
+        source: get_source_link(element),
+        items: get_impl_items(element)
+            .map(|items| get_associated_items(&items))
+            .unwrap_or_default(),
+    })
+}
+
+// Id of the <h2> heading the section the element is in.
+fn get_section_id<'a>(element: &ElementRef<'a>) -> Option<&'a str> {
+    std::iter::once(**element)
+        .chain(element.ancestors())
+        .flat_map(|node| node.prev_siblings())
+        .filter_map(ElementRef::wrap)
+        .find(|e| e.value().name() == "h2")
+        .and_then(|h2| h2.value().id())
+}
+
+// Trait pages list the types implementing the trait,
+// which are not implementations of the trait page itself.
+fn is_implementor(element: &ElementRef) -> bool {
+    let in_list = element.ancestors().filter_map(ElementRef::wrap).any(|e| {
+        matches!(
+            e.value().id(),
+            Some("implementors-list" | "synthetic-implementors-list")
+        )
+    });
+    in_list || get_section_id(element) == Some("foreign-impls")
+}
+
+pub fn parse_implementations(root: &ElementRef) -> Vec<Implementation> {
+    let selector = Selector::parse("section.impl, h3.impl").unwrap();
+    root.select(&selector)
+        .filter(|element| !is_implementor(element))
+        .filter_map(|element| parse_implementation(&element))
+        .collect()
+}
+
+// Trait pages list their own items in sections like
+// <h2 id="required-methods">Required methods</h2><div class="methods">...</div>
+pub fn parse_trait_items(root: &ElementRef) -> Vec<AssociatedItem> {
+    let selector = Selector::parse("div.methods").unwrap();
+    root.select(&selector)
+        .filter(|methods| {
+            !methods.ancestors().filter_map(ElementRef::wrap).any(|e| {
+                e.value().id() == Some("implementors-list")
+                    || e.value().classes().any(|c| c == "impl-items")
+            })
+        })
+        .flat_map(|methods| get_associated_items(&methods))
+        .collect()
+}
@@ -0,0 +1,96 @@
+use scraper::{ElementRef, Selector};
+
+use crate::{DocsType, ItemListing, ListedItem};
+
+use super::{content::parse_to_atomics, utils::text_of};
+
+// <a class="struct" href="struct.Error.html">Error</a>
+// Links without a known class are judged by their filename.
+fn get_kind_of_link(link: &ElementRef) -> Option<DocsType> {
+    link.value()
+        .classes()
+        .find_map(DocsType::from_rustdoc_class)
+        .or_else(|| {
+            let href = link.value().attr("href")?;
+            let path = href.split(['#', '?']).next().unwrap_or_default();
+            DocsType::from_filename(path.rsplit('/').next().unwrap_or_default())
+        })
+}
+
+// The row layout changed a lot between rustdoc versions:
+// <tr class="module-item"><td><a>..</a></td><td class="docblock-short">..</td></tr>
+// <div class="item-row"><div class="item-left"><a>..</a></div><div class="item-right docblock-short">..</div></div>
+// <li><div class="item-name"><a>..</a></div><div class="desc docblock-short">..</div></li>
+// <dt><a>..</a></dt><dd>..</dd>
+fn parse_row(row: &ElementRef) -> Option<(DocsType, ListedItem)> {
+    let link_selector = Selector::parse("a[href]").unwrap();
+    let stab_selector = Selector::parse(".stab").unwrap();
+    let short_selector = Selector::parse(".docblock-short").unwrap();
+
+    let name_cell = if row.value().name() == "dt" {
+        *row
+    } else {
+        row.children().filter_map(ElementRef::wrap).next()?
+    };
+    let link = name_cell.select(&link_selector).next()?;
+    let kind = get_kind_of_link(&link)?;
+    let summary_cell = if row.value().name() == "dt" {
+        row.next_siblings()
+            .filter_map(ElementRef::wrap)
+            .next()
+            .filter(|e| e.value().name() == "dd")
+    } else {
+        row.select(&short_selector).next()
+    };
+
+    Some((
+        kind,
+        ListedItem {
+            name: text_of(&link),
+            url: link.value().attr("href")?.to_string(),
+            summary: summary_cell
+                .map(|cell| parse_to_atomics(&cell))
+                .unwrap_or_default(),
+            tags: name_cell
+                .select(&stab_selector)
+                .map(|stab| text_of(&stab))
+                .filter(|t| !t.is_empty())
+                .collect(),
+        },
+    ))
+}
+
+pub fn parse_listings(root: &ElementRef) -> Vec<ItemListing> {
+    let selector = Selector::parse(concat!(
+        ".item-table > .item-row, .item-table > li, .item-table > dt, ",
+        "table tr.module-item"
+    ))
+    .unwrap();
+    ItemListing::group(
+        root.select(&selector)
+            .filter_map(|row| parse_row(&row))
+            .collect(),
+    )
+}
+
+// <h3 id="structs">Structs</h3><ul class="all-items"><li><a href="rngs/struct.OsRng.html">rngs::OsRng</a></li></ul>
+// Older versions use <ul class="structs docblock"> instead.
+// The links carry no class, so the kind comes from the filename.
+pub fn parse_all_items(main: &ElementRef) -> Vec<ItemListing> {
+    let selector = Selector::parse("ul li a[href]").unwrap();
+    ItemListing::group(
+        main.select(&selector)
+            .filter_map(|link| {
+                Some((
+                    get_kind_of_link(&link)?,
+                    ListedItem {
+                        name: text_of(&link),
+                        url: link.value().attr("href")?.to_string(),
+                        summary: vec![],
+                        tags: vec![],
+                    },
+                ))
+            })
+            .collect(),
+    )
+}
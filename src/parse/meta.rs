@@ -1,17 +1,201 @@
 use scraper::{ElementRef, Html, Selector};
 
-use crate::{DocsType, DocuPageMeta, References};
+use crate::{
+    CrateVersion, DependencyKind, DependencyReference, DocsType, DocuPageMeta, DocumentedItems,
+    OwnerReference, Percentage, PlatformReference, References, VersionReference,
+};
 
-use super::error::HtmlParseError;
+use super::{error::HtmlParseError, options::ParseOptions, utils::text_of};
 
-fn get_references(_root: &ElementRef) -> Result<References, HtmlParseError> {
+const DOCS_RS: &str = "https://docs.rs";
+
+// Links in the docs.rs navigation bar
+// are relative to the docs.rs root.
+fn absolute_docs_rs_url(href: &str) -> String {
+    if href.starts_with('/') && !href.starts_with("//") {
+        format!("{}{}", DOCS_RS, href)
+    } else {
+        href.to_string()
+    }
+}
+
+// Version requirements like "^0.4" or "~1.2.3"
+// are turned into the lowest matching version.
+// Upper bounds like "<2" and exclusive ones like ">1"
+// have no such version.
+fn parse_crate_version(requirement: &str) -> Option<CrateVersion> {
+    let requirement = requirement.trim();
+    let version = [">=", "^", "~", "="]
+        .into_iter()
+        .find_map(|operator| requirement.strip_prefix(operator))
+        .unwrap_or(requirement)
+        .trim_start();
+    if let Ok(version) = version.parse() {
+        return Some(version);
+    }
+    let numbers_end = version.find(['-', '+']).unwrap_or(version.len());
+    let missing = 2usize.saturating_sub(version[..numbers_end].matches('.').count());
+    let padded = format!(
+        "{}{}{}",
+        &version[..numbers_end],
+        ".0".repeat(missing),
+        &version[numbers_end..]
+    );
+    padded.parse().ok()
+}
+
+// The docs.rs crate menu consists of lists like
+// <ul><li class="pure-menu-heading">Owners</li><li>...</li></ul>
+fn get_menu_list<'a>(root: &ElementRef<'a>, heading: &str) -> Option<ElementRef<'a>> {
+    let list_selector = Selector::parse(".package-details-menu ul.pure-menu-list").unwrap();
+    let heading_selector = Selector::parse("li.pure-menu-heading").unwrap();
+    root.select(&list_selector).find(|list| {
+        list.select(&heading_selector)
+            .next()
+            .map(|h| text_of(&h) == heading)
+            .unwrap_or(false)
+    })
+}
+
+fn get_menu_links<'a>(root: &ElementRef<'a>, heading: &str) -> Option<Vec<ElementRef<'a>>> {
+    let link_selector = Selector::parse("a[href]").unwrap();
+    let list = get_menu_list(root, heading)?;
+    Some(list.select(&link_selector).collect())
+}
+
+fn get_crates_io(root: &ElementRef) -> Option<String> {
+    let selector =
+        Selector::parse(".package-details-menu a[href^=\"https://crates.io/crates/\"]").unwrap();
+    root.select(&selector)
+        .next()
+        .and_then(|a| a.value().attr("href"))
+        .map(|href| href.to_string())
+}
+
+fn get_repository(root: &ElementRef) -> Option<String> {
+    get_menu_links(root, "Links")?
+        .into_iter()
+        .find(|a| text_of(a) == "Repository")
+        .and_then(|a| a.value().attr("href"))
+        .map(|href| href.to_string())
+}
+
+fn get_owners(root: &ElementRef) -> Option<Vec<OwnerReference>> {
+    let owners = get_menu_links(root, "Owners")?
+        .into_iter()
+        .map(|a| OwnerReference {
+            name: text_of(&a),
+            url: a.value().attr("href").unwrap_or_default().to_string(),
+        })
+        .collect();
+    Some(owners)
+}
+
+// <a href="/log/^0.4.4">log ^0.4.4 <i class="dependencies normal">normal</i></a>
+fn get_dependencies(root: &ElementRef) -> Option<Vec<DependencyReference>> {
+    let kind_selector = Selector::parse("i.dependencies").unwrap();
+    let dependencies = get_menu_links(root, "Dependencies")?
+        .into_iter()
+        .filter_map(|a| {
+            let kind = match a.select(&kind_selector).next().map(|i| text_of(&i)) {
+                Some(k) if k == "dev" => DependencyKind::Dev,
+                Some(k) if k == "build" => DependencyKind::Build,
+                _ => DependencyKind::Normal,
+            };
+            let label = a.text().next()?.trim().to_string();
+            let (name, requirement) = label.split_once(' ').unwrap_or((&label, ""));
+            if name.is_empty() {
+                return None;
+            }
+            Some(DependencyReference {
+                name: name.to_string(),
+                kind,
+                requirement: requirement.trim().to_string(),
+                version: parse_crate_version(requirement),
+                url: absolute_docs_rs_url(a.value().attr("href")?),
+            })
+        })
+        .collect();
+    Some(dependencies)
+}
+
+fn get_versions(root: &ElementRef) -> Option<Vec<VersionReference>> {
+    let versions = get_menu_links(root, "Versions")?
+        .into_iter()
+        .filter_map(|a| {
+            Some(VersionReference {
+                version: parse_crate_version(&text_of(&a))?,
+                url: absolute_docs_rs_url(a.value().attr("href")?),
+            })
+        })
+        .collect();
+    Some(versions)
+}
+
+fn get_platforms(root: &ElementRef) -> Option<Vec<PlatformReference>> {
+    let toggle_selector = Selector::parse("a[aria-label=\"Platform\"]").unwrap();
+    let link_selector = Selector::parse("ul.pure-menu-children a[href]").unwrap();
+    let toggle = root.select(&toggle_selector).next()?;
+    let menu = toggle.parent().and_then(ElementRef::wrap)?;
+    let platforms = menu
+        .select(&link_selector)
+        .map(|a| PlatformReference {
+            name: text_of(&a),
+            url: absolute_docs_rs_url(a.value().attr("href").unwrap_or_default()),
+        })
+        .collect();
+    Some(platforms)
+}
+
+// All of those are only available on docs.rs,
+// pages built by `cargo doc` have no such navigation bar.
+fn get_references(root: &ElementRef) -> Result<References, HtmlParseError> {
     Ok(References {
-        crates_io: None,
-        dependencies: None,
-        owners: None,
-        platforms: None,
-        repository: None,
-        versions: None,
+        crates_io: get_crates_io(root),
+        dependencies: get_dependencies(root),
+        owners: get_owners(root),
+        platforms: get_platforms(root),
+        repository: get_repository(root),
+        versions: get_versions(root),
+    })
+}
+
+// "96.77%"
+fn parse_percentage(text: &str) -> Option<Percentage> {
+    let percent = text.trim().strip_suffix('%')?.trim().parse::<f32>().ok()?;
+    Percentage::from_f32_ratio(percent / 100.).ok()
+}
+
+// "45 out of 50 items documented"
+fn parse_documented_items(text: &str) -> Option<DocumentedItems> {
+    let (before, after) = text.split_once(" out of ")?;
+    let documented = before.split_whitespace().last()?.parse().ok()?;
+    let total = after.split_whitespace().next()?.parse().ok()?;
+    Some(DocumentedItems { documented, total })
+}
+
+// docs.rs shows the item counts on the crate page only.
+// <span class="documented-info"><b>45</b> out of <b>50</b> items documented...
+fn get_documented_items(root: &ElementRef) -> Option<DocumentedItems> {
+    let selector = Selector::parse(".documented-info").unwrap();
+    root.select(&selector)
+        .find_map(|e| parse_documented_items(&e.text().collect::<Vec<&str>>().join("")))
+}
+
+// <a href="/crate/rand/0.8.5"><b>100%</b> of the crate is documented</a>
+fn get_documentation_percent(
+    root: &ElementRef,
+    documented_items: &Option<DocumentedItems>,
+) -> Option<Percentage> {
+    let link_selector = Selector::parse(".package-details-menu a").unwrap();
+    let bold_selector = Selector::parse("b").unwrap();
+    let from_menu = root
+        .select(&link_selector)
+        .filter(|a| text_of(a).ends_with("of the crate is documented"))
+        .find_map(|a| parse_percentage(&text_of(&a.select(&bold_selector).next()?)));
+    from_menu.or_else(|| {
+        let items = documented_items.as_ref().filter(|i| i.total > 0)?;
+        Percentage::from_f32_ratio(items.documented as f32 / items.total as f32).ok()
     })
 }
 
@@ -28,12 +212,75 @@ fn get_title(root: &ElementRef) -> Result<String, HtmlParseError> {
         .join(""))
 }
 
-pub fn parse_meta_from_html(html: &Html) -> Result<DocuPageMeta, HtmlParseError> {
+fn get_rustdoc_classes(root: &ElementRef) -> Vec<String> {
+    let selector = Selector::parse(".rustdoc").unwrap();
+    root.select(&selector)
+        .flat_map(|e| {
+            e.value()
+                .classes()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+        })
+        .filter(|c| c != "rustdoc")
+        .collect()
+}
+
+// Tries the body classes first, then the title
+// and falls back to the filename of the page.
+fn get_page_type(
+    root: &ElementRef,
+    title: &str,
+    options: &ParseOptions,
+) -> Result<DocsType, HtmlParseError> {
+    let classes = get_rustdoc_classes(root);
+    // "crate" has to win over "mod" on crate roots
+    let by_class = if classes.iter().any(|c| c == "crate") {
+        Some(DocsType::Crate)
+    } else {
+        classes.iter().find_map(|c| DocsType::from_rustdoc_class(c))
+    };
+    if let Some(page_type) = by_class {
+        return Ok(page_type);
+    }
+
+    if let Some(page_type) = DocsType::from_title(title) {
+        return Ok(page_type);
+    }
+
+    let filename = options
+        .url
+        .as_ref()
+        .map(|url| url.split(['?', '#']).next().unwrap_or_default())
+        .and_then(|path| path.rsplit(['/', '\\']).next())
+        .unwrap_or_default();
+    if let Some(page_type) = DocsType::from_filename(filename) {
+        return Ok(page_type);
+    }
+
+    let title_prefix = title.split_whitespace().next().unwrap_or_default();
+    let observed = [
+        title_prefix.to_string(),
+        classes.join(" "),
+        filename.to_string(),
+    ]
+    .into_iter()
+    .find(|s| !s.is_empty())
+    .unwrap_or_default();
+    Err(HtmlParseError::PageTypeUnknown(observed))
+}
+
+pub fn parse_meta_from_html(
+    html: &Html,
+    options: &ParseOptions,
+) -> Result<DocuPageMeta, HtmlParseError> {
     let root = html.root_element();
+    let title = get_title(&root)?;
+    let documented_items = get_documented_items(&root);
     Ok(DocuPageMeta {
-        documentation_percent: None,
-        title: get_title(&root)?,
-        page_type: DocsType::Struct,
+        documentation_percent: get_documentation_percent(&root, &documented_items),
+        documented_items,
+        page_type: get_page_type(&root, &title, options)?,
+        title,
         references: get_references(&root)?,
     })
 }
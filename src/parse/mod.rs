@@ -1,12 +1,30 @@
 mod content;
+mod declaration;
 mod error;
+mod implementation;
+mod listing;
 mod meta;
+mod options;
+mod resolve;
+mod sidebar;
+mod utils;
 
 use scraper::{ElementRef, Html, Selector};
 
-use crate::{Content, DocuPage};
+use crate::{Content, DocuPage, ItemListing, PageLocation};
 
-use self::{content::parse_to_content, error::HtmlParseError, meta::parse_meta_from_html};
+use self::{
+    content::parse_to_content,
+    declaration::parse_item_declaration,
+    implementation::{parse_implementations, parse_trait_items},
+    listing::{parse_all_items, parse_listings},
+    meta::parse_meta_from_html,
+    resolve::Resolver,
+    sidebar::parse_sidebar,
+};
+
+pub use error::HtmlParseError;
+pub use options::{ParseOptions, ParseOptionsBuilder};
 
 // When working with scraper,
 // text elements have a lot of whitespace around them.
@@ -23,7 +41,7 @@ fn minify(html: &str) -> String {
         .into()
 }
 
-fn get_main_content(element: &ElementRef) -> Result<Content, HtmlParseError> {
+fn get_main_element<'a>(element: &ElementRef<'a>) -> Result<ElementRef<'a>, HtmlParseError> {
     let selector = Selector::parse("#main-content").unwrap();
     let content = element.select(&selector).collect::<Vec<ElementRef>>();
     if content.len() != 1 {
@@ -32,12 +50,14 @@ fn get_main_content(element: &ElementRef) -> Result<Content, HtmlParseError> {
             content.len(),
         ));
     }
-    parse_to_content(&content[0])
+    Ok(content[0])
 }
 
-pub fn parse_html(html: &str) -> Result<DocuPage, HtmlParseError> {
-    let document = Html::parse_document(minify(html).as_str());
+fn get_main_content(element: &ElementRef) -> Result<Content, HtmlParseError> {
+    parse_to_content(&get_main_element(element)?)
+}
 
+fn check_errors(document: &Html) -> Result<(), HtmlParseError> {
     let real_errors = document
         .errors
         .iter()
@@ -48,10 +68,57 @@ pub fn parse_html(html: &str) -> Result<DocuPage, HtmlParseError> {
     if !real_errors.is_empty() {
         return Err(HtmlParseError::InvalidHtml(real_errors.join("\n")));
     }
+    Ok(())
+}
+
+fn parse_document(html: &str) -> Result<Html, HtmlParseError> {
+    let document = Html::parse_document(minify(html).as_str());
+    check_errors(&document)?;
+    Ok(document)
+}
+
+pub fn parse_html(html: &str) -> Result<DocuPage, HtmlParseError> {
+    parse_html_with_options(html, &ParseOptions::default())
+}
+
+pub fn parse_html_with_options(
+    html: &str,
+    options: &ParseOptions,
+) -> Result<DocuPage, HtmlParseError> {
+    let document = parse_document(html)?;
+
+    let mut page = DocuPage {
+        content: get_main_content(&document.root_element())?,
+        meta: parse_meta_from_html(&document, options)?,
+        declaration: parse_item_declaration(&document.root_element()),
+        implementations: parse_implementations(&document.root_element()),
+        trait_items: parse_trait_items(&document.root_element()),
+        listings: parse_listings(&document.root_element()),
+        sidebar: parse_sidebar(&document.root_element()),
+        location: options.url.as_deref().and_then(PageLocation::parse),
+    };
+    if let Some(url) = &options.url {
+        Resolver::new(url, options.resolve_links).page(&mut page);
+    }
+    Ok(page)
+}
 
-    let main_content = get_main_content(&document.root_element())?;
-    Ok(DocuPage {
-        content: main_content,
-        meta: parse_meta_from_html(&document)?,
-    })
+// The "List of all items" page (all.html) of a crate.
+// The names of the items are their paths, e.g. "rngs::OsRng".
+pub fn parse_all_items_html(html: &str) -> Result<Vec<ItemListing>, HtmlParseError> {
+    let document = parse_document(html)?;
+    Ok(parse_all_items(&get_main_element(
+        &document.root_element(),
+    )?))
+}
+
+// HTML snippets instead of whole pages, e.g. from Content::to_html.
+// Not minified, so whitespace is kept as is.
+pub fn parse_content_html(html: &str) -> Result<Content, HtmlParseError> {
+    if html.trim().is_empty() {
+        return Ok(Content(vec![]));
+    }
+    let fragment = Html::parse_fragment(html);
+    check_errors(&fragment)?;
+    parse_to_content(&fragment.root_element())
 }
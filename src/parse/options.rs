@@ -0,0 +1,21 @@
+use derive_builder::Builder;
+
+// Everything the HTML alone does not tell us
+// about a page, but the caller might know.
+//
+// let options = ParseOptionsBuilder::default()
+//     .url("https://docs.rs/rand/0.8.5/rand/struct.Error.html")
+//     .build()
+//     .unwrap();
+#[derive(Debug, Clone, Default, PartialEq, Eq, Builder)]
+#[builder(default, setter(into, strip_option))]
+pub struct ParseOptions {
+    // Where the page was fetched from.
+    // Either an URL or a local file path.
+    // Relative image sources are resolved against it.
+    pub url: Option<String>,
+    // Also make all links absolute, based on the url above.
+    // Covers the content as well as declarations, impls,
+    // item listings, source links and the sidebar.
+    pub resolve_links: bool,
+}
@@ -0,0 +1,164 @@
+use crate::{
+    url::join_url, AssociatedItem, Content, DeclarationKind, DocuPage, Fields, FunctionSignature,
+    GenericParam, Implementation, SidebarLink, TextAtomic, TraitItem, WherePredicate,
+};
+
+use super::content::{resolve_image_sources, resolve_links};
+
+// Resolves the urls of everything parsed from a page
+// against where the page lives. Image sources always,
+// links only if `links` is set.
+pub struct Resolver<'a> {
+    base: &'a str,
+    links: bool,
+}
+
+impl<'a> Resolver<'a> {
+    pub fn new(base: &'a str, links: bool) -> Self {
+        Self { base, links }
+    }
+
+    fn url(&self, url: &mut String) {
+        if self.links {
+            *url = join_url(self.base, url);
+        }
+    }
+
+    fn atomics(&self, atomics: &mut [TextAtomic]) {
+        for url in atomics.iter_mut().filter_map(|a| a.url.as_mut()) {
+            self.url(url);
+        }
+    }
+
+    fn content(&self, content: &mut Content) {
+        resolve_image_sources(&mut content.0, self.base);
+        if self.links {
+            resolve_links(&mut content.0, self.base);
+        }
+    }
+
+    fn generics(&self, generics: &mut [GenericParam]) {
+        for param in generics {
+            self.atomics(&mut param.bounds);
+            param.default.iter_mut().for_each(|d| self.atomics(d));
+        }
+    }
+
+    fn where_clause(&self, where_clause: &mut [WherePredicate]) {
+        for predicate in where_clause {
+            self.atomics(&mut predicate.bounded);
+            self.atomics(&mut predicate.bounds);
+        }
+    }
+
+    fn fields(&self, fields: &mut Fields) {
+        for field in &mut fields.fields {
+            self.atomics(&mut field.ty);
+            field.docs.iter_mut().for_each(|d| self.content(d));
+        }
+    }
+
+    fn signature(&self, signature: &mut FunctionSignature) {
+        self.generics(&mut signature.generics);
+        for argument in &mut signature.arguments {
+            self.atomics(&mut argument.ty);
+        }
+        signature
+            .return_type
+            .iter_mut()
+            .for_each(|t| self.atomics(t));
+        self.where_clause(&mut signature.where_clause);
+    }
+
+    fn declaration_kind(&self, kind: &mut DeclarationKind) {
+        match kind {
+            DeclarationKind::Struct(fields) | DeclarationKind::Union(fields) => self.fields(fields),
+            DeclarationKind::Enum { variants, .. } => {
+                for variant in variants {
+                    self.fields(&mut variant.fields);
+                    variant.docs.iter_mut().for_each(|d| self.content(d));
+                }
+            }
+            DeclarationKind::Trait {
+                supertraits, items, ..
+            } => {
+                self.atomics(supertraits);
+                for item in items {
+                    match item {
+                        TraitItem::Method { signature, .. } => self.signature(signature),
+                        TraitItem::AssociatedType {
+                            bounds, default, ..
+                        } => {
+                            self.atomics(bounds);
+                            default.iter_mut().for_each(|d| self.atomics(d));
+                        }
+                        TraitItem::AssociatedConstant { ty, .. } => self.atomics(ty),
+                    }
+                }
+            }
+            DeclarationKind::Function(signature) => self.signature(signature),
+            DeclarationKind::TypeAlias { ty } => ty.iter_mut().for_each(|t| self.atomics(t)),
+            DeclarationKind::Constant { ty, .. } | DeclarationKind::Static { ty, .. } => {
+                self.atomics(ty)
+            }
+            DeclarationKind::Other(_) => (),
+        }
+    }
+
+    fn associated_item(&self, item: &mut AssociatedItem) {
+        self.atomics(&mut item.signature);
+        item.docs.iter_mut().for_each(|d| self.content(d));
+        item.source.iter_mut().for_each(|s| self.url(s));
+    }
+
+    fn implementation(&self, implementation: &mut Implementation) {
+        self.generics(&mut implementation.generics);
+        implementation
+            .implemented_trait
+            .iter_mut()
+            .for_each(|t| self.atomics(t));
+        self.atomics(&mut implementation.self_type);
+        self.where_clause(&mut implementation.where_clause);
+        implementation.source.iter_mut().for_each(|s| self.url(s));
+        for item in &mut implementation.items {
+            self.associated_item(item);
+        }
+    }
+
+    fn sidebar_link(&self, link: &mut SidebarLink) {
+        self.url(&mut link.url);
+    }
+
+    pub fn page(&self, page: &mut DocuPage) {
+        self.content(&mut page.content);
+        if let Some(declaration) = &mut page.declaration {
+            self.generics(&mut declaration.generics);
+            self.where_clause(&mut declaration.where_clause);
+            self.declaration_kind(&mut declaration.kind);
+        }
+        for implementation in &mut page.implementations {
+            self.implementation(implementation);
+        }
+        for item in &mut page.trait_items {
+            self.associated_item(item);
+        }
+        for item in page.listings.iter_mut().flat_map(|l| l.items.iter_mut()) {
+            self.url(&mut item.url);
+            self.atomics(&mut item.summary);
+        }
+        if let Some(sidebar) = &mut page.sidebar {
+            sidebar
+                .location
+                .iter_mut()
+                .for_each(|l| self.sidebar_link(l));
+            sidebar
+                .parents
+                .iter_mut()
+                .for_each(|l| self.sidebar_link(l));
+            for section in &mut sidebar.sections {
+                section.url.iter_mut().for_each(|u| self.url(u));
+                section.links.iter_mut().for_each(|l| self.sidebar_link(l));
+            }
+        }
+    }
+}
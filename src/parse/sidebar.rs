@@ -0,0 +1,114 @@
+use scraper::{ElementRef, Selector};
+
+use crate::{CrateVersion, Sidebar, SidebarLink, SidebarSection};
+
+use super::utils::text_of;
+
+fn to_link(element: &ElementRef) -> Option<SidebarLink> {
+    let link_selector = Selector::parse("a[href]").unwrap();
+    let link = if element.value().name() == "a" {
+        *element
+    } else {
+        element.select(&link_selector).next()?
+    };
+    Some(SidebarLink {
+        text: text_of(element),
+        url: link.value().attr("href")?.to_string(),
+    })
+}
+
+// "In crate rand" -> "rand"
+fn strip_parent_prefix(text: &str) -> Option<&str> {
+    ["In crate ", "In module ", "In "]
+        .iter()
+        .find_map(|prefix| text.strip_prefix(prefix))
+}
+
+// Newer versions: <div class="sidebar-crate"><h2><a>rand</a></h2><div class="version">0.8.5</div></div>
+// Older versions: <div class="block version"><p>Version 0.8.5</p></div>
+fn get_crate_version(sidebar: &ElementRef) -> Option<CrateVersion> {
+    let selector = Selector::parse(".version").unwrap();
+    sidebar.select(&selector).find_map(|version| {
+        let text = text_of(&version);
+        text.trim_start_matches("Version ").parse().ok()
+    })
+}
+
+// Titles are <h3> elements, followed by the links of the section in
+// <div class="sidebar-links"> (older) or <ul class="block"> (newer).
+fn get_sections(sidebar: &ElementRef) -> Vec<SidebarSection> {
+    let selector = Selector::parse(
+        ".sidebar-elems h3, .sidebar-elems .sidebar-links a[href], .sidebar-elems ul a[href]",
+    )
+    .unwrap();
+    let mut sections: Vec<SidebarSection> = vec![];
+    for element in sidebar.select(&selector) {
+        if element.value().name() == "h3" {
+            sections.push(SidebarSection {
+                title: Some(text_of(&element)),
+                url: to_link(&element).map(|l| l.url),
+                links: vec![],
+            });
+            continue;
+        }
+        let Some(link) = to_link(&element) else {
+            continue;
+        };
+        match sections.last_mut() {
+            Some(section) => section.links.push(link),
+            None => sections.push(SidebarSection {
+                title: None,
+                url: None,
+                links: vec![link],
+            }),
+        }
+    }
+    sections
+}
+
+pub fn parse_sidebar(root: &ElementRef) -> Option<Sidebar> {
+    let sidebar_selector = Selector::parse(".sidebar").unwrap();
+    let heading_selector = Selector::parse("h2").unwrap();
+    let crate_selector = Selector::parse(".sidebar-crate h2 a").unwrap();
+    let sidebar = root.select(&sidebar_selector).next()?;
+
+    let mut crate_name = sidebar.select(&crate_selector).next().map(|a| text_of(&a));
+    let mut location = None;
+    let mut parents = vec![];
+    let is_crate_heading = |h2: &ElementRef| {
+        h2.ancestors()
+            .filter_map(ElementRef::wrap)
+            .any(|e| e.value().classes().any(|c| c == "sidebar-crate"))
+    };
+    for heading in sidebar
+        .select(&heading_selector)
+        .filter(|h2| !is_crate_heading(h2))
+    {
+        let Some(link) = to_link(&heading) else {
+            continue;
+        };
+        if let Some(parent) = strip_parent_prefix(&link.text) {
+            parents.push(SidebarLink {
+                text: parent.to_string(),
+                url: link.url,
+            });
+        } else if location.is_none() {
+            location = Some(link);
+        }
+    }
+    // Older crate roots only say "Crate rand"
+    if crate_name.is_none() {
+        crate_name = location
+            .as_ref()
+            .and_then(|l| l.text.strip_prefix("Crate "))
+            .map(|name| name.to_string());
+    }
+
+    Some(Sidebar {
+        location,
+        crate_name,
+        crate_version: get_crate_version(&sidebar),
+        parents,
+        sections: get_sections(&sidebar),
+    })
+}
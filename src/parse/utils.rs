@@ -0,0 +1,13 @@
+use scraper::ElementRef;
+
+// All text below the element with whitespace collapsed,
+// "\n    Dependencies\n" becomes "Dependencies".
+pub(super) fn text_of(element: &ElementRef) -> String {
+    element
+        .text()
+        .collect::<Vec<&str>>()
+        .join("")
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
@@ -0,0 +1,143 @@
+use derive_builder::Builder;
+
+use crate::{Content, TextStyle};
+
+#[cfg(feature = "highlight")]
+use super::Theme;
+use super::{
+    layout::{blocks, Look, UNICODE},
+    StyledLine,
+};
+
+// What the terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    TrueColor, // 24-bit
+    Ansi256,
+    None,
+}
+
+// let options = AnsiOptionsBuilder::default()
+//     .width(100_usize)
+//     .colors(ColorSupport::Ansi256)
+//     .build()
+//     .unwrap();
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(default, setter(into))]
+pub struct AnsiOptions {
+    // Columns of the terminal
+    pub width: usize,
+    pub colors: ColorSupport,
+    // OSC 8 links, not every terminal supports them.
+    pub hyperlinks: bool,
+    // Highlights code blocks, None keeps them plain.
+    #[cfg(feature = "highlight")]
+    #[builder(setter(into, strip_option))]
+    pub theme: Option<Theme>,
+}
+
+impl Default for AnsiOptions {
+    fn default() -> Self {
+        Self {
+            width: 80,
+            colors: ColorSupport::TrueColor,
+            hyperlinks: true,
+            #[cfg(feature = "highlight")]
+            theme: None,
+        }
+    }
+}
+
+// Nearest color of the 6x6x6 cube or the gray ramp.
+fn to_ansi256((r, g, b): (u8, u8, u8)) -> u8 {
+    if r == g && g == b {
+        return match r {
+            0..=7 => 16,
+            249..=255 => 231,
+            _ => 232 + ((r as u16 - 8) * 24 / 241) as u8,
+        };
+    }
+    let level = |c: u8| (c as u16 * 5 + 127) / 255;
+    (16 + 36 * level(r) + 6 * level(g) + level(b)) as u8
+}
+
+fn color_code(layer: u8, rgb: (u8, u8, u8), colors: ColorSupport) -> Option<String> {
+    match colors {
+        ColorSupport::TrueColor => Some(format!("{};2;{};{};{}", layer, rgb.0, rgb.1, rgb.2)),
+        ColorSupport::Ansi256 => Some(format!("{};5;{}", layer, to_ansi256(rgb))),
+        ColorSupport::None => None,
+    }
+}
+
+// Select Graphic Rendition parameters,
+// e.g. "1;38;2;255;0;0" for bold red.
+fn sgr(style: &TextStyle, colors: ColorSupport) -> String {
+    let mut codes = vec![];
+    if style.is_bold() {
+        codes.push("1".to_string());
+    }
+    if style.is_italic() {
+        codes.push("3".to_string());
+    }
+    if style.is_underline() {
+        codes.push("4".to_string());
+    }
+    if style.is_strike_through() {
+        codes.push("9".to_string());
+    }
+    codes.extend(
+        style
+            .foreground_rgb()
+            .and_then(|rgb| color_code(38, rgb, colors)),
+    );
+    codes.extend(
+        style
+            .background_rgb()
+            .and_then(|rgb| color_code(48, rgb, colors)),
+    );
+    codes.join(";")
+}
+
+// Parsed documents must not be able to send their own
+// escape sequences, e.g. one setting the terminal title.
+fn strip_controls(text: &str) -> String {
+    text.chars()
+        .filter(|c| *c == '\t' || !c.is_control())
+        .collect()
+}
+
+impl StyledLine {
+    pub fn to_ansi(&self, options: &AnsiOptions) -> String {
+        let mut rendered = String::new();
+        for atomic in &self.0 {
+            let codes = sgr(&atomic.style, options.colors);
+            let link = atomic.url.as_ref().filter(|_| options.hyperlinks);
+            if let Some(url) = link {
+                rendered.push_str(&format!("\x1b]8;;{}\x1b\\", strip_controls(url)));
+            }
+            let text = strip_controls(&atomic.text);
+            match codes.is_empty() {
+                true => rendered.push_str(&text),
+                false => rendered.push_str(&format!("\x1b[{}m{}\x1b[0m", codes, text)),
+            }
+            if link.is_some() {
+                rendered.push_str("\x1b]8;;\x1b\\");
+            }
+        }
+        rendered
+    }
+}
+
+impl Content {
+    pub fn to_ansi(&self, options: &AnsiOptions) -> String {
+        let look = Look {
+            #[cfg(feature = "highlight")]
+            theme: options.theme.as_ref(),
+            ..Look::new(&UNICODE)
+        };
+        blocks(&self.0, options.width, &look)
+            .iter()
+            .map(|line| format!("{}\n", line.to_ansi(options)))
+            .collect()
+    }
+}
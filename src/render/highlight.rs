@@ -0,0 +1,658 @@
+use crate::{BlockContainer, Language, TextAtomic, TextStyle};
+
+use super::{merge_atomics, StyledLine};
+
+// What a piece of code is, the theme decides how it looks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Highlight {
+    Plain,
+    Keyword,
+    Type, // also lifetimes and TOML/INI sections
+    Function,
+    String,
+    Number,
+    Comment,
+    Attribute, // #[derive], keys of config files, variables, markup attributes
+}
+
+// Colors per kind of token.
+// Every style inherits from plain, so the
+// background of plain is the one of the whole block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub plain: TextStyle,
+    pub keyword: TextStyle,
+    pub type_name: TextStyle,
+    pub function: TextStyle,
+    pub string: TextStyle,
+    pub number: TextStyle,
+    pub comment: TextStyle,
+    pub attribute: TextStyle,
+}
+
+fn fg(rgb: (u8, u8, u8)) -> TextStyle {
+    TextStyle::default().with_foreground_rgb(rgb)
+}
+
+impl Theme {
+    // Based on Atom One Dark
+    pub fn dark() -> Self {
+        Self {
+            plain: fg((171, 178, 191)).with_background_rgb((40, 44, 52)),
+            keyword: fg((198, 120, 221)),
+            type_name: fg((229, 192, 123)),
+            function: fg((97, 175, 239)),
+            string: fg((152, 195, 121)),
+            number: fg((209, 154, 102)),
+            comment: fg((92, 99, 112)).with_italic(true),
+            attribute: fg((224, 108, 117)),
+        }
+    }
+
+    // Based on Atom One Light
+    pub fn light() -> Self {
+        Self {
+            plain: fg((56, 58, 66)).with_background_rgb((250, 250, 250)),
+            keyword: fg((166, 38, 164)),
+            type_name: fg((193, 132, 1)),
+            function: fg((64, 120, 242)),
+            string: fg((80, 161, 79)),
+            number: fg((152, 104, 1)),
+            comment: fg((160, 161, 167)).with_italic(true),
+            attribute: fg((228, 86, 73)),
+        }
+    }
+
+    pub fn style(&self, highlight: Highlight) -> TextStyle {
+        let style = match highlight {
+            Highlight::Plain => return self.plain.clone(),
+            Highlight::Keyword => &self.keyword,
+            Highlight::Type => &self.type_name,
+            Highlight::Function => &self.function,
+            Highlight::String => &self.string,
+            Highlight::Number => &self.number,
+            Highlight::Comment => &self.comment,
+            Highlight::Attribute => &self.attribute,
+        };
+        style.clone().inherit(&self.plain)
+    }
+}
+
+// How to tokenize the languages which look like code,
+// everything from Bash to Swift.
+#[derive(Default)]
+struct Syntax {
+    keywords: &'static str, // separated by spaces
+    types: &'static str,
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    case_insensitive: bool, // SQL
+    variables: bool,        // $name in Bash, Perl and PHP
+    preprocessor: bool,     // #include in C and C++
+    capitalized_types: bool,
+    rust: bool, // lifetimes, macros and #[attributes]
+    keys: bool, // JSON strings followed by ':'
+}
+
+const RUST: Syntax = Syntax {
+    keywords: "as async await break const continue crate dyn else enum extern false fn for if \
+        impl in let loop match mod move mut pub ref return self Self static struct super \
+        trait true type union unsafe use where while",
+    types: "bool char f32 f64 i8 i16 i32 i64 i128 isize str u8 u16 u32 u64 u128 usize",
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '\''],
+    case_insensitive: false,
+    variables: false,
+    preprocessor: false,
+    capitalized_types: true,
+    rust: true,
+    keys: false,
+};
+
+const C_KEYWORDS: &str =
+    "auto break case const continue default do else enum extern for goto if inline register \
+    return sizeof static struct switch typedef union volatile while NULL true false";
+const C_TYPES: &str =
+    "char double float int long short signed unsigned void bool size_t uint8_t uint16_t \
+    uint32_t uint64_t int8_t int16_t int32_t int64_t";
+const CPP_KEYWORDS: &str =
+    "auto break case catch class const constexpr continue default delete do else enum \
+    explicit extern false for friend if inline namespace new noexcept nullptr operator \
+    override private protected public return sizeof static struct switch template this \
+    throw true try typedef typename union using virtual while";
+const GO_KEYWORDS: &str =
+    "break case chan const continue default defer else fallthrough for func go goto if \
+    import interface map package range return select struct switch type var true false nil";
+const GO_TYPES: &str =
+    "bool byte error float32 float64 int int8 int16 int32 int64 rune string uint uint8 \
+    uint16 uint32 uint64";
+const JAVA_KEYWORDS: &str =
+    "abstract break case catch class continue default do else enum extends false final \
+    finally for if implements import instanceof interface new null package private \
+    protected public return static super switch synchronized this throw throws true try \
+    var void while";
+const JAVA_TYPES: &str = "boolean byte char double float int long short";
+const JS_KEYWORDS: &str =
+    "async await break case catch class const continue default delete do else export \
+    extends false finally for from function if import in instanceof let new null of return \
+    static super switch this throw true try typeof undefined var void while yield";
+const TS_KEYWORDS: &str =
+    "abstract as async await break case catch class const continue declare default do else \
+    enum export extends false finally for from function if implements import in interface \
+    keyof let new null of private protected public readonly return static super switch \
+    this throw true try type typeof undefined var while yield";
+const TS_TYPES: &str = "any bigint boolean never number object string symbol unknown void";
+const KOTLIN_KEYWORDS: &str =
+    "as break class companion continue data do else enum false for fun if import in \
+    interface is null object open override package private protected public return sealed \
+    super this throw true try val var when while";
+const LUA_KEYWORDS: &str =
+    "and break do else elseif end false for function goto if in local nil not or repeat \
+    return then true until while";
+const MATLAB_KEYWORDS: &str =
+    "break case catch classdef continue else elseif end for function global if otherwise \
+    parfor persistent return switch try while true false";
+const PERL_KEYWORDS: &str =
+    "else elsif for foreach if last local my next our package return sub unless until use \
+    while";
+const PHP_KEYWORDS: &str =
+    "abstract array as break case catch class const continue default do echo else elseif \
+    extends false final finally for foreach function if implements interface namespace new \
+    null private protected public return static switch throw trait true try use while";
+const PYTHON_KEYWORDS: &str =
+    "and as assert async await break class continue def del elif else except False finally \
+    for from global if import in is lambda None nonlocal not or pass raise return True try \
+    while with yield";
+const PYTHON_TYPES: &str = "bool bytes dict float int list object set str tuple";
+const R_KEYWORDS: &str =
+    "break else for function if in next repeat return while TRUE FALSE NULL NA Inf NaN";
+const RUBY_KEYWORDS: &str =
+    "alias and begin break case class def do else elsif end ensure false for if in module \
+    next nil not or redo require rescue retry return self super then true unless until \
+    when while yield";
+const SQL_KEYWORDS: &str =
+    "add all alter and as asc between by case create delete desc distinct drop else end \
+    exists false from group having in index inner insert into is join key left like limit \
+    not null on or order outer primary right select set table then true union update \
+    values when where";
+const SQL_TYPES: &str =
+    "bigint blob boolean char date decimal float int integer real text timestamp varchar";
+const SWIFT_KEYWORDS: &str =
+    "as break case catch class continue default defer do else enum extension false for func \
+    guard if import in init let nil private protocol public return self static struct \
+    switch throw throws true try var where while";
+const BASH_KEYWORDS: &str =
+    "case do done elif else esac export fi for function if in local return then until while";
+
+fn code_syntax(language: &Language) -> Syntax {
+    let c_like = Syntax {
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\''],
+        capitalized_types: true,
+        ..Syntax::default()
+    };
+    match language {
+        Language::Rust => RUST,
+        Language::C => Syntax {
+            keywords: C_KEYWORDS,
+            types: C_TYPES,
+            preprocessor: true,
+            capitalized_types: false,
+            ..c_like
+        },
+        Language::Cpp => Syntax {
+            keywords: CPP_KEYWORDS,
+            types: C_TYPES,
+            preprocessor: true,
+            ..c_like
+        },
+        Language::Go => Syntax {
+            keywords: GO_KEYWORDS,
+            types: GO_TYPES,
+            quotes: &['"', '\'', '`'],
+            ..c_like
+        },
+        Language::Java => Syntax {
+            keywords: JAVA_KEYWORDS,
+            types: JAVA_TYPES,
+            ..c_like
+        },
+        Language::JavaScript => Syntax {
+            keywords: JS_KEYWORDS,
+            quotes: &['"', '\'', '`'],
+            ..c_like
+        },
+        Language::TypeScript => Syntax {
+            keywords: TS_KEYWORDS,
+            types: TS_TYPES,
+            quotes: &['"', '\'', '`'],
+            ..c_like
+        },
+        Language::Kotlin => Syntax {
+            keywords: KOTLIN_KEYWORDS,
+            ..c_like
+        },
+        Language::Swift => Syntax {
+            keywords: SWIFT_KEYWORDS,
+            ..c_like
+        },
+        Language::Php => Syntax {
+            keywords: PHP_KEYWORDS,
+            line_comments: &["//", "#"],
+            variables: true,
+            ..c_like
+        },
+        Language::Lua => Syntax {
+            keywords: LUA_KEYWORDS,
+            line_comments: &["--"],
+            block_comment: Some(("--[[", "]]")),
+            quotes: &['"', '\''],
+            ..Syntax::default()
+        },
+        Language::Sql => Syntax {
+            keywords: SQL_KEYWORDS,
+            types: SQL_TYPES,
+            line_comments: &["--"],
+            block_comment: Some(("/*", "*/")),
+            quotes: &['\'', '"'],
+            case_insensitive: true,
+            ..Syntax::default()
+        },
+        Language::Matlab => Syntax {
+            keywords: MATLAB_KEYWORDS,
+            line_comments: &["%"],
+            block_comment: Some(("%{", "%}")),
+            quotes: &['\'', '"'],
+            ..Syntax::default()
+        },
+        Language::Python => Syntax {
+            keywords: PYTHON_KEYWORDS,
+            types: PYTHON_TYPES,
+            line_comments: &["#"],
+            quotes: &['"', '\''],
+            capitalized_types: true,
+            ..Syntax::default()
+        },
+        Language::Ruby => Syntax {
+            keywords: RUBY_KEYWORDS,
+            line_comments: &["#"],
+            quotes: &['"', '\''],
+            capitalized_types: true,
+            ..Syntax::default()
+        },
+        Language::R => Syntax {
+            keywords: R_KEYWORDS,
+            line_comments: &["#"],
+            quotes: &['"', '\''],
+            ..Syntax::default()
+        },
+        Language::Perl => Syntax {
+            keywords: PERL_KEYWORDS,
+            line_comments: &["#"],
+            quotes: &['"', '\''],
+            variables: true,
+            ..Syntax::default()
+        },
+        Language::Bash => Syntax {
+            keywords: BASH_KEYWORDS,
+            line_comments: &["#"],
+            quotes: &['"', '\''],
+            variables: true,
+            ..Syntax::default()
+        },
+        Language::Json => Syntax {
+            keywords: "true false null",
+            quotes: &['"'],
+            keys: true,
+            ..Syntax::default()
+        },
+        // Values of config files
+        Language::Toml | Language::Ini | Language::Yaml => Syntax {
+            keywords: "true false null yes no on off ~",
+            line_comments: &["#", ";"],
+            quotes: &['"', '\''],
+            ..Syntax::default()
+        },
+        Language::Html | Language::Xml => Syntax::default(),
+    }
+}
+
+type Token = (Highlight, String);
+
+struct Lexer<'a> {
+    chars: Vec<char>,
+    position: usize,
+    syntax: &'a Syntax,
+    tokens: Vec<Token>,
+}
+
+fn is_identifier(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+impl<'a> Lexer<'a> {
+    fn new(code: &str, syntax: &'a Syntax) -> Self {
+        Self {
+            chars: code.chars().collect(),
+            position: 0,
+            syntax,
+            tokens: vec![],
+        }
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.position + offset).copied()
+    }
+
+    fn starts_with(&self, text: &str) -> bool {
+        text.chars()
+            .enumerate()
+            .all(|(i, c)| self.peek(i) == Some(c))
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> String {
+        let start = self.position;
+        while self.peek(0).is_some_and(&f) {
+            self.position += 1;
+        }
+        self.chars[start..self.position].iter().collect()
+    }
+
+    // Up to and including the end, or everything left
+    fn take_through(&mut self, end: &str) -> String {
+        let start = self.position;
+        while self.position < self.chars.len() && !self.starts_with(end) {
+            self.position += 1;
+        }
+        self.position = (self.position + end.chars().count()).min(self.chars.len());
+        self.chars[start..self.position].iter().collect()
+    }
+
+    fn push(&mut self, highlight: Highlight, text: String) {
+        match self.tokens.last_mut() {
+            Some((last, previous)) if *last == highlight => previous.push_str(&text),
+            _ => self.tokens.push((highlight, text)),
+        }
+    }
+
+    fn at_word_start(&self) -> bool {
+        self.position == 0 || !is_identifier(self.chars[self.position - 1])
+    }
+
+    // Comments like "#" need whitespace in front,
+    // "$#" in Bash is a variable.
+    fn after_whitespace(&self) -> bool {
+        self.position == 0 || self.chars[self.position - 1].is_whitespace()
+    }
+
+    // "fn max<T>(" is a function, even with generics in between
+    fn after_definition(&self) -> bool {
+        match self.tokens.as_slice() {
+            [.., (Highlight::Keyword, keyword), (Highlight::Plain, space)] => {
+                space.trim().is_empty()
+                    && ["fn", "func", "fun", "function", "def", "sub"].contains(&keyword.as_str())
+            }
+            _ => false,
+        }
+    }
+
+    fn at_line_start(&self) -> bool {
+        self.chars[..self.position]
+            .iter()
+            .rev()
+            .take_while(|c| **c != '\n')
+            .all(|c| c.is_whitespace())
+    }
+
+    fn string(&mut self, quote: char) -> String {
+        // Python and the like, """docs"""
+        let triple = quote.to_string().repeat(3);
+        if quote != '\'' && self.starts_with(&triple) {
+            self.position += 3;
+            return format!("{}{}", triple, self.take_through(&triple));
+        }
+        let start = self.position;
+        self.position += 1;
+        while let Some(c) = self.peek(0) {
+            self.position += 1;
+            if c == '\\' {
+                self.position = (self.position + 1).min(self.chars.len());
+            } else if c == quote {
+                break;
+            }
+        }
+        self.chars[start..self.position].iter().collect()
+    }
+
+    // 'a is a lifetime, 'a' a character
+    fn is_lifetime(&self) -> bool {
+        self.peek(1).is_some_and(|c| c.is_alphabetic() || c == '_') && self.peek(2) != Some('\'')
+    }
+
+    fn next_non_space(&self) -> Option<char> {
+        self.chars[self.position..]
+            .iter()
+            .find(|c| !c.is_whitespace())
+            .copied()
+    }
+
+    fn identifier(&mut self) {
+        let word = self.take_while(is_identifier);
+        let is = |list: &str| match self.syntax.case_insensitive {
+            true => list.split(' ').any(|k| k.eq_ignore_ascii_case(&word)),
+            false => list.split(' ').any(|k| k == word),
+        };
+        let highlight = if is(self.syntax.keywords) {
+            Highlight::Keyword
+        } else if is(self.syntax.types)
+            || (self.syntax.capitalized_types && word.starts_with(char::is_uppercase))
+        {
+            Highlight::Type
+        } else if self.syntax.rust && self.peek(0) == Some('!') && self.peek(1) != Some('=') {
+            // Macros, "println!"
+            self.position += 1;
+            self.push(Highlight::Function, format!("{}!", word));
+            return;
+        } else if self.next_non_space() == Some('(') || self.after_definition() {
+            Highlight::Function
+        } else {
+            Highlight::Plain
+        };
+        self.push(highlight, word);
+    }
+
+    fn run(mut self) -> Vec<Token> {
+        while let Some(c) = self.peek(0) {
+            let syntax = self.syntax;
+            if let Some((start, end)) = syntax.block_comment.filter(|(s, _)| self.starts_with(s)) {
+                self.position += start.chars().count();
+                let comment = format!("{}{}", start, self.take_through(end));
+                self.push(Highlight::Comment, comment);
+            } else if syntax
+                .line_comments
+                .iter()
+                .any(|l| self.starts_with(l) && (l.len() > 1 || self.after_whitespace()))
+            {
+                let comment = self.take_while(|c| c != '\n');
+                self.push(Highlight::Comment, comment);
+            } else if syntax.preprocessor && c == '#' && self.at_line_start() {
+                let directive = self.take_while(|c| c != '\n');
+                self.push(Highlight::Attribute, directive);
+            } else if syntax.rust && (self.starts_with("#[") || self.starts_with("#![")) {
+                let attribute = self.take_through("]");
+                self.push(Highlight::Attribute, attribute);
+            } else if syntax.rust && c == '\'' && self.is_lifetime() {
+                self.position += 1;
+                let name = self.take_while(is_identifier);
+                self.push(Highlight::Type, format!("'{}", name));
+            } else if syntax.quotes.contains(&c) {
+                let string = self.string(c);
+                let highlight = match syntax.keys && self.next_non_space() == Some(':') {
+                    true => Highlight::Attribute,
+                    false => Highlight::String,
+                };
+                self.push(highlight, string);
+            } else if syntax.variables && c == '$' && self.peek(1).is_some_and(is_identifier) {
+                self.position += 1;
+                let name = self.take_while(is_identifier);
+                self.push(Highlight::Attribute, format!("${}", name));
+            } else if c.is_ascii_digit() && self.at_word_start() {
+                let start = self.position;
+                while let Some(c) = self.peek(0) {
+                    // 1.5 and 0xFF_u8, but not the range 1..2
+                    let fraction = c == '.' && self.peek(1).is_some_and(|n| n.is_ascii_digit());
+                    if !(is_identifier(c) || fraction) {
+                        break;
+                    }
+                    self.position += 1;
+                }
+                let number = self.chars[start..self.position].iter().collect();
+                self.push(Highlight::Number, number);
+            } else if c.is_alphabetic() || c == '_' {
+                self.identifier();
+            } else {
+                self.position += 1;
+                self.push(Highlight::Plain, c.to_string());
+            }
+        }
+        self.tokens
+    }
+}
+
+// TOML, INI and YAML go line by line:
+// [sections], keys and values lexed like code.
+fn lex_config(code: &str, language: &Language) -> Vec<Token> {
+    let syntax = code_syntax(language);
+    let separator = match language {
+        Language::Yaml => ':',
+        _ => '=',
+    };
+    let mut tokens = vec![];
+    for (i, line) in code.split('\n').enumerate() {
+        if i > 0 {
+            tokens.push((Highlight::Plain, "\n".to_string()));
+        }
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+        tokens.push((Highlight::Plain, indent.to_string()));
+        if trimmed.starts_with('#') || trimmed.starts_with(';') {
+            tokens.push((Highlight::Comment, trimmed.to_string()));
+            continue;
+        }
+        if trimmed.starts_with('[') && *language != Language::Yaml {
+            let end = trimmed.rfind(']').map(|e| e + 1).unwrap_or(trimmed.len());
+            tokens.push((Highlight::Type, trimmed[..end].to_string()));
+            tokens.extend(Lexer::new(&trimmed[end..], &syntax).run());
+            continue;
+        }
+        let (marker, rest) = match trimmed.strip_prefix("- ") {
+            Some(rest) if *language == Language::Yaml => ("- ", rest),
+            _ => ("", trimmed),
+        };
+        tokens.push((Highlight::Plain, marker.to_string()));
+        // Keys before the separator, as long as no value started
+        let key_end = rest
+            .find(separator)
+            .filter(|end| !rest[..*end].contains(['"', '\'', '[', '{']));
+        let value = match key_end {
+            Some(end) => {
+                tokens.push((Highlight::Attribute, rest[..end].to_string()));
+                &rest[end..]
+            }
+            None => rest,
+        };
+        tokens.extend(Lexer::new(value, &syntax).run());
+    }
+    tokens
+}
+
+// HTML and XML: <tag attribute="value">text</tag>
+fn lex_markup(code: &str) -> Vec<Token> {
+    let syntax = Syntax::default();
+    let mut lexer = Lexer::new(code, &syntax);
+    while let Some(c) = lexer.peek(0) {
+        if lexer.starts_with("<!--") {
+            let comment = lexer.take_through("-->");
+            lexer.push(Highlight::Comment, comment);
+        } else if c == '<' {
+            let open = lexer.take_while(|c| c == '<' || c == '/');
+            lexer.push(Highlight::Plain, open);
+            let name = lexer.take_while(|c| !c.is_whitespace() && !"/>".contains(c));
+            lexer.push(Highlight::Keyword, name);
+            while let Some(c) = lexer.peek(0) {
+                if c == '>' {
+                    break;
+                }
+                if c == '"' || c == '\'' {
+                    let value = lexer.string(c);
+                    lexer.push(Highlight::String, value);
+                } else if c.is_whitespace() || "=/?".contains(c) {
+                    lexer.position += 1;
+                    lexer.push(Highlight::Plain, c.to_string());
+                } else {
+                    let attribute = lexer.take_while(|c| !c.is_whitespace() && !"=/>".contains(c));
+                    lexer.push(Highlight::Attribute, attribute);
+                }
+            }
+            let close = lexer.take_while(|c| c == '>');
+            lexer.push(Highlight::Plain, close);
+        } else {
+            let text = lexer.take_while(|c| c != '<');
+            lexer.push(Highlight::Plain, text);
+        }
+    }
+    lexer.tokens
+}
+
+fn lex(code: &str, language: &Language) -> Vec<Token> {
+    match language {
+        Language::Html | Language::Xml => lex_markup(code),
+        Language::Toml | Language::Ini | Language::Yaml => lex_config(code, language),
+        _ => Lexer::new(code, &code_syntax(language)).run(),
+    }
+}
+
+// Code without a language is kept plain,
+// but still gets the colors of the theme.
+pub fn highlight(code: &str, language: Option<&Language>, theme: &Theme) -> Vec<StyledLine> {
+    let code = code.trim_end_matches('\n');
+    let tokens = match language {
+        Some(language) => lex(code, language),
+        None => vec![(Highlight::Plain, code.to_string())],
+    };
+    let mut lines = vec![vec![]];
+    for (highlight, text) in tokens {
+        let style = theme.style(highlight);
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(vec![]);
+            }
+            if !part.is_empty() {
+                lines.last_mut().unwrap().push(TextAtomic {
+                    text: part.to_string(),
+                    style: style.clone(),
+                    url: None,
+                });
+            }
+        }
+    }
+    lines
+        .iter()
+        .map(|line| StyledLine(merge_atomics(line)))
+        .collect()
+}
+
+impl BlockContainer {
+    // None for everything but code blocks.
+    pub fn highlight(&self, theme: &Theme) -> Option<Vec<StyledLine>> {
+        match self {
+            BlockContainer::Code { code, language } => {
+                Some(highlight(code, language.as_ref(), theme))
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use html_escape::{encode_double_quoted_attribute, encode_text};
+
+use crate::{BlockContainer, CalloutKind, Content, Table, TableAlignment, TableCell, TextAtomic};
+
+fn is_bare(atomic: &TextAtomic) -> bool {
+    let style = &atomic.style;
+    atomic.url.is_none()
+        && !(style.is_code()
+            || style.is_bold()
+            || style.is_italic()
+            || style.is_strike_through()
+            || style.is_underline())
+}
+
+// Every atomic gets its own elements, so the parser
+// splits the text the same way again.
+// Colors have no semantic HTML and are left out.
+fn inline(atomics: &[TextAtomic]) -> String {
+    let mut rendered = String::new();
+    let mut previous_bare = false;
+    for atomic in atomics {
+        let style = &atomic.style;
+        let mut html = encode_text(&atomic.text).to_string();
+        let tags = [
+            (style.is_underline(), "u"),
+            (style.is_strike_through(), "del"),
+            (style.is_italic(), "em"),
+            (style.is_bold(), "strong"),
+            (style.is_code(), "code"),
+        ];
+        for (_, tag) in tags.iter().filter(|(enabled, _)| *enabled) {
+            html = format!("<{tag}>{html}</{tag}>");
+        }
+        if let Some(url) = &atomic.url {
+            html = format!(
+                "<a href=\"{}\">{}</a>",
+                encode_double_quoted_attribute(url),
+                html
+            );
+        }
+        // Two texts in a row would become one
+        let bare = is_bare(atomic);
+        if bare && previous_bare {
+            html = format!("<span>{}</span>", html);
+        }
+        previous_bare = bare;
+        rendered.push_str(&html);
+    }
+    rendered
+}
+
+fn attribute(name: &str, value: &str) -> String {
+    format!(" {}=\"{}\"", name, encode_double_quoted_attribute(value))
+}
+
+fn cell(cell: &TableCell) -> String {
+    let tag = if cell.header { "th" } else { "td" };
+    let mut attributes = String::new();
+    if let Some(alignment) = &cell.alignment {
+        let value = match alignment {
+            TableAlignment::Left => "left",
+            TableAlignment::Center => "center",
+            TableAlignment::Right => "right",
+        };
+        attributes.push_str(&attribute("style", &format!("text-align: {}", value)));
+    }
+    if cell.colspan != 1 {
+        attributes.push_str(&attribute("colspan", &cell.colspan.to_string()));
+    }
+    if cell.rowspan != 1 {
+        attributes.push_str(&attribute("rowspan", &cell.rowspan.to_string()));
+    }
+    format!("<{tag}{attributes}>{}</{tag}>", inline(&cell.content))
+}
+
+fn rows(rows: &[Vec<TableCell>]) -> String {
+    rows.iter()
+        .map(|row| format!("<tr>{}</tr>", row.iter().map(cell).collect::<String>()))
+        .collect()
+}
+
+fn table(table: &Table) -> String {
+    let mut rendered = "<table>".to_string();
+    if !table.header.is_empty() {
+        rendered.push_str(&format!("<thead>{}</thead>", rows(&table.header)));
+    }
+    if !table.body.is_empty() {
+        rendered.push_str(&format!("<tbody>{}</tbody>", rows(&table.body)));
+    }
+    rendered.push_str("</table>");
+    rendered
+}
+
+fn block(block: &BlockContainer) -> String {
+    match block {
+        BlockContainer::Heading1(a) => format!("<h1>{}</h1>", inline(a)),
+        BlockContainer::Heading2(a) => format!("<h2>{}</h2>", inline(a)),
+        BlockContainer::Heading3(a) => format!("<h3>{}</h3>", inline(a)),
+        BlockContainer::Heading4(a) => format!("<h4>{}</h4>", inline(a)),
+        BlockContainer::Paragraph(a) => format!("<p>{}</p>", inline(a)),
+        BlockContainer::Quote(content) => format!("<blockquote>{}</blockquote>", blocks(content)),
+        BlockContainer::Code { code, language } => {
+            let class = match language {
+                Some(l) => attribute("class", &format!("language-{}", l.as_str())),
+                None => "".to_string(),
+            };
+            format!("<pre><code{}>{}</code></pre>", class, encode_text(code))
+        }
+        BlockContainer::BulletPoints {
+            points,
+            enumerated,
+            start,
+        } => {
+            let items = points
+                .iter()
+                .map(|point| format!("<li>{}</li>", blocks(point)))
+                .collect::<String>();
+            match (enumerated, start) {
+                (false, _) => format!("<ul>{}</ul>", items),
+                (true, Some(start)) if *start != 1 => {
+                    format!(
+                        "<ol{}>{}</ol>",
+                        attribute("start", &start.to_string()),
+                        items
+                    )
+                }
+                (true, _) => format!("<ol>{}</ol>", items),
+            }
+        }
+        BlockContainer::Table(t) => table(t),
+        BlockContainer::Callout { kind, content } => {
+            let kind = match kind {
+                CalloutKind::Warning => "warning",
+                CalloutKind::Note => "note",
+                CalloutKind::Deprecated => "deprecated",
+                CalloutKind::Unstable => "unstable",
+                CalloutKind::Portability => "portability",
+                CalloutKind::EmptyImpl => "empty-impl",
+            };
+            format!(
+                "<aside{}>{}</aside>",
+                attribute("class", &format!("callout {}", kind)),
+                blocks(content)
+            )
+        }
+        BlockContainer::Image {
+            url,
+            alt,
+            title,
+            width,
+            height,
+            link,
+        } => {
+            let mut attributes = attribute("src", url);
+            let optional = [
+                ("alt", alt.clone()),
+                ("title", title.clone()),
+                ("width", width.map(|w| w.to_string())),
+                ("height", height.map(|h| h.to_string())),
+            ];
+            for (name, value) in optional {
+                if let Some(value) = value {
+                    attributes.push_str(&attribute(name, &value));
+                }
+            }
+            match link {
+                Some(link) => format!("<a{}><img{}></a>", attribute("href", link), attributes),
+                None => format!("<img{}>", attributes),
+            }
+        }
+    }
+}
+
+fn blocks(blocks: &[BlockContainer]) -> String {
+    blocks.iter().map(block).collect()
+}
+
+impl Content {
+    // Plain semantic HTML to style on your own.
+    // parse_content_html turns it back into the same content.
+    pub fn to_html(&self) -> String {
+        blocks(&self.0)
+    }
+}
@@ -0,0 +1,513 @@
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::{
+    BlockContainer, CalloutKind, Content, Language, Table, TableAlignment, TableCell, TextAtomic,
+    TextStyle,
+};
+
+use super::merge_atomics;
+#[cfg(feature = "highlight")]
+use super::{highlight, Theme};
+
+// One line of terminal output.
+// The atomics keep style and url, so it can be
+// handed to any TUI framework instead of to_ansi.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StyledLine(pub Vec<TextAtomic>);
+
+// What lists, quotes and tables are drawn with.
+pub(super) struct Glyphs {
+    bullet: &'static str,
+    quote: &'static str,
+    callout: &'static str,
+    horizontal: &'static str,
+    vertical: &'static str,
+    top: [&'static str; 3], // left, between columns, right
+    separator: [&'static str; 3],
+    bottom: [&'static str; 3],
+}
+
+pub(super) const UNICODE: Glyphs = Glyphs {
+    bullet: "• ",
+    quote: "│ ",
+    callout: "┃ ",
+    horizontal: "─",
+    vertical: "│",
+    top: ["┌", "┬", "┐"],
+    separator: ["├", "┼", "┤"],
+    bottom: ["└", "┴", "┘"],
+};
+
+pub(super) const ASCII: Glyphs = Glyphs {
+    bullet: "* ",
+    quote: "> ",
+    callout: "| ",
+    horizontal: "-",
+    vertical: "|",
+    top: ["+", "+", "+"],
+    separator: ["+", "+", "+"],
+    bottom: ["+", "+", "+"],
+};
+
+// Everything about the looks of the output, besides its width.
+pub(super) struct Look<'a> {
+    pub glyphs: &'a Glyphs,
+    // Highlights code blocks, None keeps them plain.
+    #[cfg(feature = "highlight")]
+    pub theme: Option<&'a Theme>,
+}
+
+impl<'a> Look<'a> {
+    pub fn new(glyphs: &'a Glyphs) -> Self {
+        Self {
+            glyphs,
+            #[cfg(feature = "highlight")]
+            theme: None,
+        }
+    }
+}
+
+impl StyledLine {
+    // Columns the line takes up,
+    // CJK characters and emoji take two.
+    pub fn width(&self) -> usize {
+        self.0.iter().map(|atomic| atomic.text.width()).sum()
+    }
+
+    // Without any styling
+    pub fn text(&self) -> String {
+        self.0.iter().map(|atomic| atomic.text.as_str()).collect()
+    }
+}
+
+fn plain(text: &str) -> TextAtomic {
+    TextAtomic::simple(&text)
+}
+
+// Adds the style to every atomic,
+// without overwriting what they set themselves.
+fn with_style(atomics: &[TextAtomic], style: &TextStyle) -> Vec<TextAtomic> {
+    atomics
+        .iter()
+        .map(|atomic| TextAtomic {
+            style: atomic.style.clone().inherit(style),
+            ..atomic.clone()
+        })
+        .collect()
+}
+
+// A word may consist of multiple atomics, e.g. "foo<b>bar</b>".
+// The whitespace in front of it keeps its style,
+// so underlined links stay underlined in between words.
+struct Word {
+    space: Option<TextAtomic>,
+    pieces: Vec<TextAtomic>,
+}
+
+fn split_words(atomics: &[TextAtomic]) -> Vec<Word> {
+    let mut words: Vec<Word> = vec![];
+    let mut space: Option<TextAtomic> = None;
+    let mut in_word = false;
+    for atomic in atomics {
+        let mut new_piece = true;
+        for c in atomic.text.chars() {
+            if c.is_whitespace() {
+                in_word = false;
+                space = Some(TextAtomic {
+                    text: " ".to_string(),
+                    ..atomic.clone()
+                });
+                continue;
+            }
+            if !in_word {
+                words.push(Word {
+                    space: space.take(),
+                    pieces: vec![],
+                });
+                in_word = true;
+                new_piece = true;
+            }
+            let word = words.last_mut().unwrap();
+            if new_piece {
+                word.pieces.push(TextAtomic {
+                    text: String::new(),
+                    ..atomic.clone()
+                });
+                new_piece = false;
+            }
+            word.pieces.last_mut().unwrap().text.push(c);
+        }
+    }
+    words
+}
+
+fn char_width(c: char) -> usize {
+    c.width().unwrap_or(0)
+}
+
+fn push_char(line: &mut Vec<TextAtomic>, atomic: &TextAtomic, c: char) {
+    match line.last_mut() {
+        Some(last) if last.style == atomic.style && last.url == atomic.url => last.text.push(c),
+        _ => line.push(TextAtomic {
+            text: c.to_string(),
+            ..atomic.clone()
+        }),
+    }
+}
+
+// Greedy word wrap. Words longer than a line are broken up.
+fn wrap(atomics: &[TextAtomic], width: usize) -> Vec<StyledLine> {
+    let width = width.max(1);
+    let mut lines = vec![];
+    let mut line: Vec<TextAtomic> = vec![];
+    let mut used = 0;
+    for word in split_words(atomics) {
+        let length: usize = word.pieces.iter().map(|p| p.text.width()).sum();
+        if used > 0 && used + 1 + length > width {
+            lines.push(StyledLine(merge_atomics(&line)));
+            line = vec![];
+            used = 0;
+        }
+        if used > 0 {
+            line.push(word.space.unwrap_or_else(|| plain(" ")));
+            used += 1;
+        }
+        for piece in &word.pieces {
+            for c in piece.text.chars() {
+                if used > 0 && used + char_width(c) > width {
+                    lines.push(StyledLine(merge_atomics(&line)));
+                    line = vec![];
+                    used = 0;
+                }
+                push_char(&mut line, piece, c);
+                used += char_width(c);
+            }
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(StyledLine(merge_atomics(&line)));
+    }
+    lines
+}
+
+// The first line gets the first prefix, all others the second one.
+// Empty lines get the trimmed prefix.
+fn prefix(lines: Vec<StyledLine>, first: &str, rest: &str) -> Vec<StyledLine> {
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let prefix = if i == 0 { first } else { rest };
+            let prefix = match line.0.is_empty() {
+                true => prefix.trim_end(),
+                false => prefix,
+            };
+            let mut atomics = vec![];
+            if !prefix.is_empty() {
+                atomics.push(plain(prefix));
+            }
+            atomics.extend(line.0);
+            StyledLine(atomics)
+        })
+        .collect()
+}
+
+// Hard breaks the line into lines of at most `width` columns.
+fn break_line(line: &StyledLine, width: usize) -> Vec<StyledLine> {
+    let mut lines = vec![];
+    let mut current = vec![];
+    let mut used = 0;
+    for atomic in &line.0 {
+        for c in atomic.text.chars() {
+            if used > 0 && used + char_width(c) > width {
+                lines.push(StyledLine(std::mem::take(&mut current)));
+                used = 0;
+            }
+            push_char(&mut current, atomic, c);
+            used += char_width(c);
+        }
+    }
+    lines.push(StyledLine(current));
+    lines
+}
+
+#[cfg(feature = "highlight")]
+fn highlighted(code: &str, language: Option<&Language>, look: &Look) -> Option<Vec<StyledLine>> {
+    look.theme.map(|theme| highlight(code, language, theme))
+}
+
+#[cfg(not(feature = "highlight"))]
+fn highlighted(_: &str, _: Option<&Language>, _: &Look) -> Option<Vec<StyledLine>> {
+    None
+}
+
+fn code_block(
+    code: &str,
+    language: Option<&Language>,
+    width: usize,
+    look: &Look,
+) -> Vec<StyledLine> {
+    let style = TextStyle::default().with_code(true);
+    let lines = match highlighted(code, language, look) {
+        Some(lines) => lines
+            .iter()
+            .map(|line| StyledLine(with_style(&line.0, &style)))
+            .collect(),
+        None => code
+            .trim_end_matches('\n')
+            .lines()
+            .map(|line| {
+                StyledLine(vec![TextAtomic {
+                    style: style.clone(),
+                    ..plain(line)
+                }])
+            })
+            .collect::<Vec<StyledLine>>(),
+    };
+    // Keep indentation, but break lines too long
+    let lines = lines
+        .iter()
+        .flat_map(|line| break_line(line, width.saturating_sub(4).max(1)))
+        .collect();
+    prefix(lines, "    ", "    ")
+}
+
+fn list(
+    points: &[Vec<BlockContainer>],
+    enumerated: bool,
+    start: Option<u64>,
+    width: usize,
+    look: &Look,
+) -> Vec<StyledLine> {
+    let start = start.unwrap_or(1);
+    let markers = (0..points.len() as u64)
+        .map(|i| match enumerated {
+            true => format!("{}. ", start + i),
+            false => look.glyphs.bullet.to_string(),
+        })
+        .collect::<Vec<String>>();
+    // Numbers are aligned right, "9." and "10." end in the same column
+    let indent = markers.iter().map(|m| m.width()).max().unwrap_or(0);
+    let mut lines = vec![];
+    for (point, marker) in points.iter().zip(markers) {
+        let marker = format!("{:>indent$}", marker, indent = indent);
+        let mut body = blocks(point, width.saturating_sub(indent), look);
+        // Empty points keep their marker
+        if body.is_empty() {
+            body.push(StyledLine::default());
+        }
+        lines.extend(prefix(body, &marker, &" ".repeat(indent)));
+    }
+    lines
+}
+
+fn callout(
+    kind: &CalloutKind,
+    content: &[BlockContainer],
+    width: usize,
+    look: &Look,
+) -> Vec<StyledLine> {
+    let mut lines = vec![];
+    let label = match kind {
+        CalloutKind::Warning => Some("Warning"),
+        CalloutKind::Note => Some("Note"),
+        _ => None,
+    };
+    if let Some(label) = label {
+        lines.push(StyledLine(vec![TextAtomic {
+            style: TextStyle::default().with_bold(true),
+            ..plain(label)
+        }]));
+    }
+    lines.extend(blocks(content, width.saturating_sub(2), look));
+    prefix(lines, look.glyphs.callout, look.glyphs.callout)
+}
+
+// Cells spanning multiple columns are followed by empty ones.
+fn table_row(
+    cells: &[TableCell],
+    columns: usize,
+) -> Vec<(Vec<TextAtomic>, Option<TableAlignment>)> {
+    let header_style = TextStyle::default().with_bold(true);
+    let mut row = vec![];
+    for cell in cells {
+        let content = match cell.header {
+            true => with_style(&cell.content, &header_style),
+            false => cell.content.clone(),
+        };
+        row.push((content, cell.alignment.clone()));
+        for _ in 1..cell.colspan.max(1) {
+            row.push((vec![], None));
+        }
+    }
+    row.resize(columns.max(row.len()), (vec![], None));
+    row
+}
+
+fn pad(line: StyledLine, width: usize, alignment: &Option<TableAlignment>) -> Vec<TextAtomic> {
+    let space = width.saturating_sub(line.width());
+    let (left, right) = match alignment {
+        Some(TableAlignment::Right) => (space, 0),
+        Some(TableAlignment::Center) => (space / 2, space - space / 2),
+        _ => (0, space),
+    };
+    let mut atomics = vec![plain(&" ".repeat(left))];
+    atomics.extend(line.0);
+    atomics.push(plain(&" ".repeat(right)));
+    atomics
+}
+
+fn border(widths: &[usize], horizontal: &str, [left, middle, right]: [&str; 3]) -> StyledLine {
+    let segments = widths
+        .iter()
+        .map(|w| horizontal.repeat(w + 2))
+        .collect::<Vec<String>>();
+    StyledLine(vec![plain(&format!(
+        "{}{}{}",
+        left,
+        segments.join(middle),
+        right
+    ))])
+}
+
+fn table(table: &Table, width: usize, glyphs: &Glyphs) -> Vec<StyledLine> {
+    let columns = table
+        .header
+        .iter()
+        .chain(table.body.iter())
+        .map(|row| row.iter().map(|cell| cell.colspan.max(1) as usize).sum())
+        .max()
+        .unwrap_or(0);
+    if columns == 0 {
+        return vec![];
+    }
+    let header = table
+        .header
+        .iter()
+        .map(|row| table_row(row, columns))
+        .collect::<Vec<_>>();
+    let body = table
+        .body
+        .iter()
+        .map(|row| table_row(row, columns))
+        .collect::<Vec<_>>();
+
+    // Shrink the widest columns until the table fits,
+    // "│ a │ b │" takes 3 columns per cell and one more.
+    let mut widths = vec![1; columns];
+    for row in header.iter().chain(body.iter()) {
+        for (i, (content, _)) in row.iter().enumerate() {
+            let length = content.iter().map(|a| a.text.width()).sum();
+            widths[i] = widths[i].max(length);
+        }
+    }
+    let available = width.saturating_sub(3 * columns + 1).max(columns);
+    while widths.iter().sum::<usize>() > available {
+        let widest = (0..columns)
+            .max_by_key(|&i| (widths[i], columns - i))
+            .unwrap();
+        widths[widest] -= 1;
+    }
+
+    let render_row = |row: &Vec<(Vec<TextAtomic>, Option<TableAlignment>)>| {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|((content, _), w)| wrap(content, *w))
+            .collect::<Vec<_>>();
+        let height = cells.iter().map(|c| c.len()).max().unwrap_or(1);
+        (0..height)
+            .map(|i| {
+                let mut atomics = vec![plain(&format!("{} ", glyphs.vertical))];
+                for (column, ((_, alignment), w)) in row.iter().zip(&widths).enumerate() {
+                    if column > 0 {
+                        atomics.push(plain(&format!(" {} ", glyphs.vertical)));
+                    }
+                    let line = cells[column].get(i).cloned().unwrap_or_default();
+                    atomics.extend(pad(line, *w, alignment));
+                }
+                atomics.push(plain(&format!(" {}", glyphs.vertical)));
+                StyledLine(merge_atomics(&atomics))
+            })
+            .collect::<Vec<StyledLine>>()
+    };
+
+    let mut lines = vec![border(&widths, glyphs.horizontal, glyphs.top)];
+    for row in &header {
+        lines.extend(render_row(row));
+    }
+    if !header.is_empty() && !body.is_empty() {
+        lines.push(border(&widths, glyphs.horizontal, glyphs.separator));
+    }
+    for row in &body {
+        lines.extend(render_row(row));
+    }
+    lines.push(border(&widths, glyphs.horizontal, glyphs.bottom));
+    lines
+}
+
+fn block(block: &BlockContainer, width: usize, look: &Look) -> Vec<StyledLine> {
+    let heading = |atomics: &[TextAtomic], underline: bool| {
+        let style = TextStyle::default()
+            .with_bold(true)
+            .with_underline(underline);
+        wrap(&with_style(atomics, &style), width)
+    };
+    match block {
+        BlockContainer::Heading1(a) => heading(a, true),
+        BlockContainer::Heading2(a) => heading(a, true),
+        BlockContainer::Heading3(a) => heading(a, false),
+        BlockContainer::Heading4(a) => heading(a, false),
+        BlockContainer::Paragraph(a) => wrap(a, width),
+        BlockContainer::Quote(content) => {
+            let lines = blocks(content, width.saturating_sub(2), look);
+            prefix(lines, look.glyphs.quote, look.glyphs.quote)
+        }
+        BlockContainer::Code { code, language } => code_block(code, language.as_ref(), width, look),
+        BlockContainer::BulletPoints {
+            points,
+            enumerated,
+            start,
+        } => list(points, *enumerated, *start, width, look),
+        BlockContainer::Table(t) => table(t, width, look.glyphs),
+        BlockContainer::Callout { kind, content } => callout(kind, content, width, look),
+        // Terminals can not show images,
+        // but at least link to them.
+        BlockContainer::Image { url, alt, .. } => {
+            let text = format!("[image: {}]", alt.as_deref().unwrap_or(url));
+            wrap(&[plain(&text).with_some_url(url)], width)
+        }
+    }
+}
+
+// Blocks are separated by an empty line.
+pub(super) fn blocks(blocks: &[BlockContainer], width: usize, look: &Look) -> Vec<StyledLine> {
+    let mut lines = vec![];
+    for rendered in blocks.iter().map(|b| block(b, width, look)) {
+        if rendered.is_empty() {
+            continue;
+        }
+        if !lines.is_empty() {
+            lines.push(StyledLine::default());
+        }
+        lines.extend(rendered);
+    }
+    lines
+}
+
+impl Content {
+    // Lays out the content for a terminal with the given columns.
+    pub fn to_styled_lines(&self, width: usize) -> Vec<StyledLine> {
+        blocks(&self.0, width, &Look::new(&UNICODE))
+    }
+
+    // Same as to_styled_lines, with code blocks highlighted.
+    #[cfg(feature = "highlight")]
+    pub fn to_highlighted_lines(&self, width: usize, theme: &Theme) -> Vec<StyledLine> {
+        let look = Look {
+            theme: Some(theme),
+            ..Look::new(&UNICODE)
+        };
+        blocks(&self.0, width, &look)
+    }
+}
@@ -0,0 +1,395 @@
+use crate::{
+    BlockContainer, CalloutKind, Content, DocsType, DocuPage, Table, TableAlignment, TableCell,
+    TextAtomic,
+};
+
+use super::merge_atomics;
+
+// Characters which would otherwise start emphasis,
+// code, links, html, entities or strike through.
+const ESCAPED: [char; 10] = ['\\', '`', '*', '_', '[', ']', '<', '>', '&', '~'];
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if ESCAPED.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// "# no heading", "- no list", "1. no list"
+fn escape_line_start(line: String) -> String {
+    if line.starts_with(['#', '+', '-', '=']) {
+        return format!("\\{}", line);
+    }
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    match line[digits..].chars().next() {
+        Some('.') | Some(')') if digits > 0 => {
+            format!("{}\\{}", &line[..digits], &line[digits..])
+        }
+        _ => line,
+    }
+}
+
+fn longest_run(text: &str, c: char) -> usize {
+    text.split(|x| x != c)
+        .map(|run| run.len())
+        .max()
+        .unwrap_or(0)
+}
+
+// The fence has to be longer than any backtick run inside,
+// "a `b`" becomes "`` a `b` ``".
+fn code_span(text: &str) -> String {
+    let text = text.replace('\n', " ");
+    let fence = "`".repeat(longest_run(&text, '`') + 1);
+    let padding = if text.starts_with('`')
+        || text.ends_with('`')
+        || (text.starts_with(' ') && text.ends_with(' ') && !text.trim().is_empty())
+    {
+        " "
+    } else {
+        ""
+    };
+    format!("{fence}{padding}{text}{padding}{fence}")
+}
+
+// Link destinations with spaces or parentheses
+// need to be wrapped in angle brackets.
+fn destination(url: &str) -> String {
+    if url.contains(|c: char| c.is_whitespace() || c == '(' || c == ')') {
+        format!("<{}>", url.replace('<', "%3C").replace('>', "%3E"))
+    } else {
+        url.to_string()
+    }
+}
+
+fn styled(atomic: &TextAtomic) -> String {
+    let style = &atomic.style;
+    if style.is_code() {
+        return code_span(&atomic.text);
+    }
+    let text = atomic.text.replace('\n', " ");
+    // "** a**" is no emphasis, whitespace has to stay outside.
+    let core = text.trim();
+    if core.is_empty() {
+        return text;
+    }
+    let start = text.len() - text.trim_start().len();
+    let (leading, trailing) = (&text[..start], &text[start + core.len()..]);
+
+    let mut rendered = escape(core);
+    if style.is_strike_through() {
+        rendered = format!("~~{}~~", rendered);
+    }
+    if style.is_italic() {
+        rendered = format!("*{}*", rendered);
+    }
+    if style.is_bold() {
+        rendered = format!("**{}**", rendered);
+    }
+    if style.is_underline() {
+        rendered = format!("<u>{}</u>", rendered);
+    }
+    format!("{}{}{}", leading, rendered, trailing)
+}
+
+fn inline(atomics: &[TextAtomic]) -> String {
+    let merged = merge_atomics(atomics);
+    let mut rendered = String::new();
+    let mut index = 0;
+    while index < merged.len() {
+        let url = &merged[index].url;
+        // Differently styled parts of one link share the brackets
+        let count = merged[index..]
+            .iter()
+            .take_while(|atomic| &atomic.url == url)
+            .count();
+        let text = merged[index..index + count]
+            .iter()
+            .map(styled)
+            .collect::<String>();
+        match url {
+            Some(url) => {
+                // "Wow![link](x)" would be an image
+                if rendered.ends_with('!') {
+                    rendered.insert(rendered.len() - 1, '\\');
+                }
+                rendered.push_str(&format!("[{}]({})", text, destination(url)))
+            }
+            None => rendered.push_str(&text),
+        }
+        index += count;
+    }
+    rendered
+}
+
+// Prefixes every line, empty lines get the trimmed prefix
+// to not end with whitespace.
+fn prefix_lines(text: &str, prefix: &str) -> String {
+    text.lines()
+        .map(|line| match line.is_empty() {
+            true => prefix.trim_end().to_string(),
+            false => format!("{}{}", prefix, line),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn code_block(code: &str, language: &str) -> String {
+    let fence = "`".repeat(longest_run(code, '`').max(2) + 1);
+    format!(
+        "{fence}{language}\n{}\n{fence}",
+        code.trim_end_matches('\n')
+    )
+}
+
+fn list(points: &[Vec<BlockContainer>], enumerated: bool, start: Option<u64>) -> String {
+    // Loose lists get blank lines between their points
+    let tight = points.iter().all(|point| {
+        point.len() <= 1 && !matches!(point.first(), Some(BlockContainer::Code { .. }))
+    });
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, point)| {
+            let marker = match enumerated {
+                true => format!("{}. ", start.unwrap_or(1) + i as u64),
+                false => "- ".to_string(),
+            };
+            let body = blocks(point);
+            if body.is_empty() {
+                return marker.trim_end().to_string();
+            }
+            let indent = " ".repeat(marker.len());
+            let indented = prefix_lines(&body, &indent);
+            format!(
+                "{}{}",
+                marker,
+                indented.strip_prefix(&indent).unwrap_or(&indented)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(if tight { "\n" } else { "\n\n" })
+}
+
+// Cells spanning multiple columns are followed by empty ones,
+// GFM has no colspan.
+fn table_row(cells: &[TableCell], columns: usize) -> Vec<(String, Option<TableAlignment>)> {
+    let mut row = vec![];
+    for cell in cells {
+        // "|" splits cells even within code spans
+        let text = inline(&cell.content).replace('|', "\\|");
+        row.push((text, cell.alignment.clone()));
+        for _ in 1..cell.colspan.max(1) {
+            row.push((String::new(), cell.alignment.clone()));
+        }
+    }
+    row.resize(columns.max(row.len()), (String::new(), None));
+    row
+}
+
+fn table(table: &Table) -> String {
+    let rows = table.header.iter().chain(table.body.iter());
+    let columns = rows
+        .map(|row| row.iter().map(|cell| cell.colspan.max(1) as usize).sum())
+        .max()
+        .unwrap_or(0);
+    if columns == 0 {
+        return String::new();
+    }
+    let format_row = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+
+    // GFM tables have exactly one header row,
+    // further ones become body rows.
+    let (header, body) = match table.header.split_first() {
+        Some((header, rest)) => (
+            table_row(header, columns),
+            rest.iter().chain(table.body.iter()).collect::<Vec<_>>(),
+        ),
+        None => (
+            vec![(String::new(), None); columns],
+            table.body.iter().collect::<Vec<_>>(),
+        ),
+    };
+    let mut alignments = header.iter().map(|(_, a)| a.clone()).collect::<Vec<_>>();
+    if table.header.is_empty() {
+        if let Some(first) = body.first() {
+            alignments = table_row(first, columns)
+                .into_iter()
+                .map(|(_, a)| a)
+                .collect();
+        }
+    }
+
+    let mut lines = vec![format_row(header.into_iter().map(|(t, _)| t).collect())];
+    lines.push(format_row(
+        alignments
+            .iter()
+            .map(|alignment| match alignment {
+                None => "---",
+                Some(TableAlignment::Left) => ":---",
+                Some(TableAlignment::Center) => ":---:",
+                Some(TableAlignment::Right) => "---:",
+            })
+            .map(|a| a.to_string())
+            .collect(),
+    ));
+    for row in body {
+        lines.push(format_row(
+            table_row(row, columns)
+                .into_iter()
+                .map(|(t, _)| t)
+                .collect(),
+        ));
+    }
+    lines.join("\n")
+}
+
+fn block(block: &BlockContainer) -> String {
+    match block {
+        BlockContainer::Heading1(a) => format!("# {}", inline(a)),
+        BlockContainer::Heading2(a) => format!("## {}", inline(a)),
+        BlockContainer::Heading3(a) => format!("### {}", inline(a)),
+        BlockContainer::Heading4(a) => format!("#### {}", inline(a)),
+        BlockContainer::Paragraph(a) => escape_line_start(inline(a)),
+        BlockContainer::Quote(content) => prefix_lines(&blocks(content), "> "),
+        BlockContainer::Code { code, language } => code_block(
+            code,
+            language.as_ref().map(|l| l.as_str()).unwrap_or_default(),
+        ),
+        BlockContainer::BulletPoints {
+            points,
+            enumerated,
+            start,
+        } => list(points, *enumerated, *start),
+        BlockContainer::Table(t) => table(t),
+        // GitHub alerts, the rest are plain quotes
+        BlockContainer::Callout { kind, content } => {
+            let body = blocks(content);
+            let body = match kind {
+                CalloutKind::Warning => format!("[!WARNING]\n{}", body),
+                CalloutKind::Note => format!("[!NOTE]\n{}", body),
+                _ => body,
+            };
+            prefix_lines(&body, "> ")
+        }
+        BlockContainer::Image {
+            url,
+            alt,
+            title,
+            link,
+            ..
+        } => {
+            let title = match title {
+                Some(t) => format!(" \"{}\"", t.replace('"', "\\\"")),
+                None => "".to_string(),
+            };
+            let image = format!(
+                "![{}]({}{})",
+                escape(alt.as_deref().unwrap_or_default()),
+                destination(url),
+                title
+            );
+            match link {
+                Some(link) => format!("[{}]({})", image, destination(link)),
+                None => image,
+            }
+        }
+    }
+}
+
+fn blocks(blocks: &[BlockContainer]) -> String {
+    blocks
+        .iter()
+        .map(block)
+        .filter(|b| !b.is_empty())
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+// YAML strings, always quoted to not
+// turn versions like 1.0 into numbers.
+fn yaml_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl TextAtomic {
+    pub fn to_markdown(&self) -> String {
+        inline(std::slice::from_ref(self))
+    }
+}
+
+impl BlockContainer {
+    // Without trailing newline.
+    pub fn to_markdown(&self) -> String {
+        block(self)
+    }
+}
+
+impl Content {
+    // CommonMark with GitHub flavored tables,
+    // strike through and alerts.
+    pub fn to_markdown(&self) -> String {
+        let rendered = blocks(&self.0);
+        match rendered.is_empty() {
+            true => rendered,
+            false => format!("{}\n", rendered),
+        }
+    }
+}
+
+impl DocuPage {
+    // The content preceded by a front matter
+    // block with what is known about the page.
+    pub fn to_markdown(&self) -> String {
+        // rustdoc calls crate roots modules, we do not
+        let kind = match self.meta.page_type {
+            DocsType::Crate => "crate",
+            ref page_type => page_type.as_rustdoc_str(),
+        };
+        let mut front_matter = vec![
+            ("title", self.meta.title.clone()),
+            ("kind", kind.to_string()),
+        ];
+        let sidebar = self.sidebar.as_ref();
+        let crate_name = match &self.location {
+            Some(location) => Some(location.crate_name.clone()),
+            None => sidebar.and_then(|s| s.crate_name.clone()),
+        };
+        let crate_version = match &self.location {
+            Some(location) => Some(location.crate_version.to_string()),
+            None => sidebar.and_then(|s| s.crate_version.as_ref().map(|v| v.to_string())),
+        };
+        let optional = [
+            ("crate", crate_name),
+            ("version", crate_version),
+            ("url", self.location.as_ref().map(|l| l.to_url())),
+            (
+                "documentation",
+                self.meta.documentation_percent.as_ref().map(|p| {
+                    let hundredths = (p.as_f32_ratio() * 10_000.).round() as f64;
+                    format!("{}%", hundredths / 100.)
+                }),
+            ),
+            ("repository", self.meta.references.repository.clone()),
+            ("crates_io", self.meta.references.crates_io.clone()),
+        ];
+        front_matter.extend(
+            optional
+                .into_iter()
+                .filter_map(|(key, value)| Some((key, value?))),
+        );
+
+        let mut rendered = "---\n".to_string();
+        for (key, value) in front_matter {
+            rendered.push_str(&format!("{}: {}\n", key, yaml_string(&value)));
+        }
+        rendered.push_str("---\n\n");
+        rendered.push_str(&self.content.to_markdown());
+        rendered
+    }
+}
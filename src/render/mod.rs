@@ -0,0 +1,32 @@
+// Turning parsed pages back into text,
+// the inverse of the parse module.
+mod ansi;
+#[cfg(feature = "highlight")]
+mod highlight;
+mod html;
+mod layout;
+mod markdown;
+mod text;
+
+use crate::TextAtomic;
+
+pub use ansi::{AnsiOptions, AnsiOptionsBuilder, ColorSupport};
+#[cfg(feature = "highlight")]
+pub use highlight::{highlight, Highlight, Theme};
+pub use layout::StyledLine;
+
+// The parser may split text with the same look,
+// e.g. "a" and "b" of "<b>a</b><b>b</b>".
+// Renderers get along better with them joined.
+fn merge_atomics(atomics: &[TextAtomic]) -> Vec<TextAtomic> {
+    let mut merged: Vec<TextAtomic> = vec![];
+    for atomic in atomics {
+        match merged.last_mut() {
+            Some(last) if last.style == atomic.style && last.url == atomic.url => {
+                last.text.push_str(&atomic.text)
+            }
+            _ => merged.push(atomic.clone()),
+        }
+    }
+    merged
+}
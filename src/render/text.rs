@@ -0,0 +1,108 @@
+use crate::{BlockContainer, Content, Table, TableCell, TextAtomic};
+
+use super::layout::{blocks, Look, ASCII};
+
+// Links become markers like "[1]", their urls are
+// listed at the end. The same url keeps its number.
+#[derive(Default)]
+struct Footnotes(Vec<String>);
+
+impl Footnotes {
+    fn number(&mut self, url: &str) -> usize {
+        match self.0.iter().position(|known| known == url) {
+            Some(index) => index + 1,
+            None => {
+                self.0.push(url.to_string());
+                self.0.len()
+            }
+        }
+    }
+
+    fn atomics(&mut self, atomics: &[TextAtomic]) -> Vec<TextAtomic> {
+        let mut replaced = vec![];
+        for (i, atomic) in atomics.iter().enumerate() {
+            replaced.push(atomic.clone().with_url(None));
+            let Some(url) = &atomic.url else {
+                continue;
+            };
+            // Only after the last part of the link
+            if atomics
+                .get(i + 1)
+                .is_some_and(|next| next.url.as_ref() == Some(url))
+            {
+                continue;
+            }
+            replaced.push(TextAtomic::simple(&format!("[{}]", self.number(url))));
+        }
+        replaced
+    }
+
+    fn blocks(&mut self, blocks: &[BlockContainer]) -> Vec<BlockContainer> {
+        blocks.iter().map(|block| self.block(block)).collect()
+    }
+
+    fn rows(&mut self, rows: &[Vec<TableCell>]) -> Vec<Vec<TableCell>> {
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| TableCell {
+                        content: self.atomics(&cell.content),
+                        ..cell.clone()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn block(&mut self, block: &BlockContainer) -> BlockContainer {
+        match block {
+            BlockContainer::Heading1(a) => BlockContainer::Heading1(self.atomics(a)),
+            BlockContainer::Heading2(a) => BlockContainer::Heading2(self.atomics(a)),
+            BlockContainer::Heading3(a) => BlockContainer::Heading3(self.atomics(a)),
+            BlockContainer::Heading4(a) => BlockContainer::Heading4(self.atomics(a)),
+            BlockContainer::Paragraph(a) => BlockContainer::Paragraph(self.atomics(a)),
+            BlockContainer::Quote(content) => BlockContainer::Quote(self.blocks(content)),
+            BlockContainer::BulletPoints {
+                points,
+                enumerated,
+                start,
+            } => BlockContainer::BulletPoints {
+                points: points.iter().map(|point| self.blocks(point)).collect(),
+                enumerated: *enumerated,
+                start: *start,
+            },
+            BlockContainer::Table(table) => BlockContainer::Table(Table {
+                header: self.rows(&table.header),
+                body: self.rows(&table.body),
+            }),
+            BlockContainer::Callout { kind, content } => BlockContainer::Callout {
+                kind: kind.clone(),
+                content: self.blocks(content),
+            },
+            // Code has no links and images stay "[image: alt]"
+            BlockContainer::Code { .. } | BlockContainer::Image { .. } => block.clone(),
+        }
+    }
+}
+
+impl Content {
+    // No escape codes, ASCII tables and links as footnotes.
+    // Always the same output for the same content.
+    pub fn to_plain_text(&self, width: usize) -> String {
+        let mut footnotes = Footnotes::default();
+        let content = footnotes.blocks(&self.0);
+
+        let mut rendered = String::new();
+        for line in blocks(&content, width, &Look::new(&ASCII)) {
+            rendered.push_str(line.text().trim_end());
+            rendered.push('\n');
+        }
+        if !footnotes.0.is_empty() {
+            rendered.push('\n');
+        }
+        for (i, url) in footnotes.0.iter().enumerate() {
+            rendered.push_str(&format!("[{}] {}\n", i + 1, url));
+        }
+        rendered
+    }
+}
@@ -0,0 +1,120 @@
+// Small helpers for working with links,
+// since we do not want to pull in a full URL crate.
+
+// "https:", "mailto:", "data:", ...
+// but not the drive letter of "C:\docs".
+fn has_scheme(url: &str) -> bool {
+    match url.find(':') {
+        Some(i) => {
+            let scheme = &url[..i];
+            scheme.len() > 1
+                && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+        }
+        None => false,
+    }
+}
+
+// "C:\docs\index.html" or "C:/docs/index.html"
+fn has_drive_prefix(url: &str) -> bool {
+    let mut chars = url.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.next() == Some(':')
+        && chars.next().is_some_and(|c| c == '\\' || c == '/')
+}
+
+// Splits "https://docs.rs/rand/index.html?x#y"
+// into ("https://docs.rs", "/rand/index.html", "?x#y").
+// Local paths have an empty origin.
+fn split_url(url: &str) -> (&str, &str, &str) {
+    let origin_end = match url.find("://") {
+        Some(i) => url[i + 3..]
+            .find('/')
+            .map(|j| i + 3 + j)
+            .unwrap_or(url.len()),
+        None => 0,
+    };
+    let (origin, rest) = url.split_at(origin_end);
+    let path_end = rest.find(['?', '#']).unwrap_or(rest.len());
+    let (path, suffix) = rest.split_at(path_end);
+    (origin, path, suffix)
+}
+
+// Resolves "." and ".." segments.
+// "a/b/../c" becomes "a/c".
+fn normalize_path(path: &str) -> String {
+    let absolute = path.starts_with('/');
+    let mut segments: Vec<&str> = Vec::new();
+    let parts = path.split('/').collect::<Vec<&str>>();
+    for (i, segment) in parts.iter().enumerate() {
+        match *segment {
+            "." => (),
+            ".." => {
+                if segments.last().map(|s| *s != "..").unwrap_or(false) {
+                    segments.pop();
+                } else if !absolute {
+                    segments.push("..");
+                }
+            }
+            "" if i != parts.len() - 1 => (),
+            s => segments.push(s),
+        }
+    }
+    // "a/b/.." is a directory, keep the trailing slash
+    if matches!(parts.last(), Some(&".") | Some(&"..")) {
+        segments.push("");
+    }
+    let joined = segments.join("/");
+    if absolute {
+        format!("/{}", joined)
+    } else {
+        joined
+    }
+}
+
+// Resolves a link found on a page against the page location,
+// which might be a URL or a local file path.
+// join_url("https://docs.rs/rand/0.8.5/rand/struct.Error.html", "../rand_core/index.html")
+// is "https://docs.rs/rand/0.8.5/rand_core/index.html".
+pub fn join_url(base: &str, reference: &str) -> String {
+    if has_scheme(reference) || has_drive_prefix(reference) {
+        return reference.to_string();
+    }
+    // Windows understands forward slashes just as well
+    let base = match has_drive_prefix(base) {
+        true => base.replace('\\', "/"),
+        false => base.to_string(),
+    };
+    let base = base.as_str();
+    let (origin, base_path, base_suffix) = split_url(base);
+    if let Some(rest) = reference.strip_prefix("//") {
+        let scheme = origin.split("://").next().unwrap_or("https");
+        return format!("{}://{}", scheme, rest);
+    }
+    if reference.is_empty() {
+        return base.to_string();
+    }
+    if reference.starts_with('#') {
+        let without_fragment = base.split('#').next().unwrap_or(base);
+        return format!("{}{}", without_fragment, reference);
+    }
+    if reference.starts_with('?') {
+        return format!("{}{}", &base[..base.len() - base_suffix.len()], reference);
+    }
+
+    let path_end = reference.find(['?', '#']).unwrap_or(reference.len());
+    let (reference_path, reference_suffix) = reference.split_at(path_end);
+    let path = if reference_path.starts_with('/') {
+        normalize_path(reference_path)
+    } else {
+        let directory = match base_path.rfind('/') {
+            Some(i) => &base_path[..i + 1],
+            None if !origin.is_empty() => "/",
+            None => "",
+        };
+        normalize_path(&format!("{}{}", directory, reference_path))
+    };
+    format!("{}{}{}", origin, path, reference_suffix)
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{DocsType, HtmlParseError, ItemListing, ListedItem};
+
+fn all_items_page(main_content: &str) -> String {
+    common::custom_rustdoc_page(
+        "rustdoc mod",
+        "<span class=\"in-band\">List of all items</span>",
+        main_content,
+    )
+}
+
+fn item(name: &str, url: &str) -> ListedItem {
+    ListedItem {
+        name: name.to_string(),
+        url: url.to_string(),
+        summary: vec![],
+        tags: vec![],
+    }
+}
+
+fn expected() -> Vec<ItemListing> {
+    vec![
+        ItemListing {
+            kind: DocsType::Struct,
+            items: vec![
+                item("Error", "struct.Error.html"),
+                item("rngs::OsRng", "rngs/struct.OsRng.html"),
+            ],
+        },
+        ItemListing {
+            kind: DocsType::Trait,
+            items: vec![item("Rng", "trait.Rng.html")],
+        },
+        ItemListing {
+            kind: DocsType::Macro,
+            items: vec![item("seq::index", "seq/macro.index.html")],
+        },
+        ItemListing {
+            kind: DocsType::Function,
+            items: vec![item("random", "fn.random.html")],
+        },
+    ]
+}
+
+#[test]
+fn all_items_list() {
+    let parsed = rust_html_doc_parse::parse_all_items_html(&all_items_page(concat!(
+        "<h3 id=\"structs\">Structs</h3><ul class=\"all-items\">",
+        "<li><a href=\"struct.Error.html\">Error</a></li>",
+        "<li><a href=\"rngs/struct.OsRng.html\">rngs::OsRng</a></li></ul>",
+        "<h3 id=\"traits\">Traits</h3><ul class=\"all-items\">",
+        "<li><a href=\"trait.Rng.html\">Rng</a></li></ul>",
+        "<h3 id=\"macros\">Macros</h3><ul class=\"all-items\">",
+        "<li><a href=\"seq/macro.index.html\">seq::index</a></li></ul>",
+        "<h3 id=\"functions\">Functions</h3><ul class=\"all-items\">",
+        "<li><a href=\"fn.random.html\">random</a></li></ul>",
+    )))
+    .unwrap();
+    assert_eq!(parsed, expected());
+}
+
+#[test]
+fn older_docblock_lists() {
+    let parsed = rust_html_doc_parse::parse_all_items_html(&all_items_page(concat!(
+        "<h3 id=\"structs\">Structs</h3><ul class=\"structs docblock\">",
+        "<li><a href=\"struct.Error.html\">Error</a></li>",
+        "<li><a href=\"rngs/struct.OsRng.html\">rngs::OsRng</a></li></ul>",
+        "<h3 id=\"traits\">Traits</h3><ul class=\"traits docblock\">",
+        "<li><a href=\"trait.Rng.html\">Rng</a></li></ul>",
+        "<h3 id=\"macros\">Macros</h3><ul class=\"macros docblock\">",
+        "<li><a href=\"seq/macro.index.html\">seq::index</a></li></ul>",
+        "<h3 id=\"functions\">Functions</h3><ul class=\"functions docblock\">",
+        "<li><a href=\"fn.random.html\">random</a></li></ul>",
+    )))
+    .unwrap();
+    assert_eq!(parsed, expected());
+}
+
+#[test]
+fn page_without_main_content() {
+    match rust_html_doc_parse::parse_all_items_html(
+        "<!DOCTYPE html><html><body><p>nothing</p></body></html>",
+    ) {
+        Err(HtmlParseError::ElementCountNotOne(selector, count)) => {
+            assert_eq!((selector, count), ("#main-content", 0))
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+}
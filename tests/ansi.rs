@@ -0,0 +1,224 @@
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{
+    AnsiOptions, AnsiOptionsBuilder, BlockContainer, CalloutKind, ColorSupport, Content,
+    StyledLine, Table, TableAlignment, TableCell, TextAtomic, TextAtomicBuilder,
+};
+
+fn text(text: &str) -> TextAtomic {
+    TextAtomic::simple(&text)
+}
+
+fn lines(content: Vec<BlockContainer>, width: usize) -> Vec<String> {
+    Content(content)
+        .to_styled_lines(width)
+        .iter()
+        .map(StyledLine::text)
+        .collect()
+}
+
+#[test]
+fn wraps_on_words_and_keeps_styles() {
+    let content = Content(vec![BlockContainer::Paragraph(vec![
+        text("The quick "),
+        TextAtomicBuilder::new(&"brown").bold(true).build(),
+        text(" fox jumps over the lazy dog"),
+    ])]);
+    let lines = content.to_styled_lines(16);
+    assert_eq!(
+        lines,
+        vec![
+            StyledLine(vec![
+                text("The quick "),
+                TextAtomicBuilder::new(&"brown").bold(true).build(),
+            ]),
+            StyledLine(vec![text("fox jumps over")]),
+            StyledLine(vec![text("the lazy dog")]),
+        ]
+    );
+}
+
+#[test]
+fn breaks_long_words() {
+    let content = vec![BlockContainer::Paragraph(vec![text(
+        "a rand::distributions::Uniform b",
+    )])];
+    assert_eq!(
+        lines(content, 12),
+        vec!["a", "rand::distri", "butions::Uni", "form b"]
+    );
+}
+
+#[test]
+fn lists_quotes_and_callouts() {
+    let paragraph = |t: &str| vec![BlockContainer::Paragraph(vec![text(t)])];
+    let content = vec![
+        BlockContainer::BulletPoints {
+            points: vec![paragraph("one two three"), paragraph("four")],
+            enumerated: true,
+            start: Some(9),
+        },
+        BlockContainer::Quote(vec![BlockContainer::BulletPoints {
+            points: vec![paragraph("quoted")],
+            enumerated: false,
+            start: None,
+        }]),
+        BlockContainer::Callout {
+            kind: CalloutKind::Warning,
+            content: paragraph("Careful"),
+        },
+    ];
+    assert_eq!(
+        lines(content, 12),
+        vec![
+            " 9. one two",
+            "    three",
+            "10. four",
+            "",
+            "│ • quoted",
+            "",
+            "┃ Warning",
+            "┃ Careful",
+        ]
+    );
+}
+
+#[test]
+fn empty_list_points_keep_their_marker() {
+    let point = |t: &str| vec![BlockContainer::Paragraph(vec![text(t)])];
+    let list = |enumerated: bool| BlockContainer::BulletPoints {
+        points: vec![point("one"), vec![], point("three")],
+        enumerated,
+        start: None,
+    };
+    assert_eq!(
+        lines(vec![list(true), list(false)], 12),
+        vec!["1. one", "2.", "3. three", "", "• one", "•", "• three"]
+    );
+}
+
+#[test]
+fn box_tables() {
+    let cell = |t: &str| TableCell::new(vec![text(t)]);
+    let content = vec![BlockContainer::Table(Table {
+        header: vec![vec![
+            cell("Name").with_header(true),
+            cell("Value")
+                .with_header(true)
+                .with_alignment(Some(TableAlignment::Right)),
+        ]],
+        body: vec![
+            vec![
+                cell("a"),
+                cell("1").with_alignment(Some(TableAlignment::Right)),
+            ],
+            vec![
+                cell("a longer name"),
+                cell("22").with_alignment(Some(TableAlignment::Right)),
+            ],
+        ],
+    })];
+    assert_eq!(
+        lines(content, 20),
+        vec![
+            "┌──────────┬───────┐",
+            "│ Name     │ Value │",
+            "├──────────┼───────┤",
+            "│ a        │     1 │",
+            "│ a longer │    22 │",
+            "│ name     │       │",
+            "└──────────┴───────┘",
+        ]
+    );
+}
+
+#[test]
+fn code_blocks_and_images() {
+    let content = vec![
+        BlockContainer::Code {
+            code: "fn main() {\n\n}\n".to_string(),
+            language: None,
+        },
+        BlockContainer::Image {
+            url: "logo.png".to_string(),
+            alt: Some("logo".to_string()),
+            title: None,
+            width: None,
+            height: None,
+            link: None,
+        },
+    ];
+    assert_eq!(
+        lines(content, 80),
+        vec!["    fn main() {", "", "    }", "", "[image: logo]"]
+    );
+}
+
+#[test]
+fn wide_characters_take_two_columns() {
+    let cell = |t: &str| TableCell::new(vec![text(t)]);
+    let content = vec![
+        BlockContainer::Paragraph(vec![text("漢字と かな 🦀🦀 ok")]),
+        BlockContainer::Code {
+            code: "let s = \"日本語\";".to_string(),
+            language: None,
+        },
+        BlockContainer::Table(Table {
+            header: vec![vec![cell("名前"), cell("x")]],
+            body: vec![vec![cell("a"), cell("🦀")]],
+        }),
+    ];
+    assert_eq!(
+        lines(content.clone(), 12),
+        vec![
+            "漢字と かな",
+            "🦀🦀 ok",
+            "",
+            "    let s = ",
+            "    \"日本語\"",
+            "    ;",
+            "",
+            "┌─────┬────┐",
+            "│ 名  │ x  │",
+            "│ 前  │    │",
+            "├─────┼────┤",
+            "│ a   │ 🦀 │",
+            "└─────┴────┘",
+        ]
+    );
+    for line in Content(content).to_styled_lines(12) {
+        assert!(line.width() <= 12, "{:?}", line.text());
+    }
+}
+
+#[test]
+fn escape_sequences() {
+    let line = StyledLine(vec![
+        TextAtomicBuilder::new(&"red")
+            .bold(true)
+            .foreground_rgb(255, 0, 0)
+            .build(),
+        text(" "),
+        TextAtomicBuilder::new(&"docs")
+            .set_url(&"https://docs.rs")
+            .build(),
+    ]);
+    assert_eq!(
+        line.to_ansi(&AnsiOptions::default()),
+        "\x1b[1;38;2;255;0;0mred\x1b[0m \x1b]8;;https://docs.rs\x1b\\docs\x1b]8;;\x1b\\"
+    );
+    let options = AnsiOptionsBuilder::default()
+        .colors(ColorSupport::Ansi256)
+        .hyperlinks(false)
+        .build()
+        .unwrap();
+    assert_eq!(line.to_ansi(&options), "\x1b[1;38;5;196mred\x1b[0m docs");
+
+    // Control characters from the document never reach the terminal
+    let embedded = StyledLine(vec![TextAtomicBuilder::new(&"a\x1b]0;pwned\x07b\u{9b}2J")
+        .set_url(&"https://docs.rs/\x1b]0;x\x07")
+        .build()]);
+    assert_eq!(
+        embedded.to_ansi(&AnsiOptions::default()),
+        "\x1b]8;;https://docs.rs/]0;x\x1b\\a]0;pwnedb2J\x1b]8;;\x1b\\"
+    );
+}
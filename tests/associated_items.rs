@@ -0,0 +1,127 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{AssociatedItemKind, Stability, TextAtomic};
+
+#[test]
+fn items_of_rand_error() {
+    let docs = include_str!("resources/docs.rs_rand_0.8.5_rand_struct.Error.html");
+    let parsed = rust_html_doc_parse::parse_html(docs).unwrap();
+
+    assert_eq!(parsed.associated_items().len(), 30);
+
+    let custom_start = parsed
+        .associated_item("#associatedconstant.CUSTOM_START")
+        .unwrap();
+    assert_eq!(custom_start.kind, AssociatedItemKind::Constant);
+    assert_eq!(
+        custom_start.summary().as_deref(),
+        Some(
+            "Codes at or above this point can be used by users to define their own custom errors."
+        )
+    );
+
+    let fmt = parsed.associated_item("method.fmt").unwrap();
+    assert_eq!(
+        fmt.summary().as_deref(),
+        Some("Formats the value using the given formatter.")
+    );
+
+    let description = parsed.associated_item("method.description").unwrap();
+    assert_eq!(
+        description.stability,
+        Stability {
+            stable_since: Some("1.0.0".to_string()),
+            deprecated: Some(
+                "Deprecated since 1.42.0: use the Display impl or to_string()".to_string()
+            ),
+            unstable: None,
+            portability: None,
+        }
+    );
+    assert!(description.stability.is_deprecated());
+    assert_eq!(description.summary(), None);
+
+    let cause = parsed.associated_item("method.cause").unwrap();
+    assert_eq!(cause.docs, None);
+    assert!(cause.stability.is_deprecated());
+
+    let backtrace = parsed.associated_item("method.backtrace").unwrap();
+    assert_eq!(
+        backtrace.stability.unstable.as_deref(),
+        Some("This is a nightly-only experimental API. (backtrace)")
+    );
+    assert_eq!(backtrace.stability.stable_since, None);
+
+    // "const: unstable" is no stable version
+    let borrow = parsed.associated_item("method.borrow").unwrap();
+    assert_eq!(borrow.stability, Stability::default());
+}
+
+#[test]
+fn items_of_a_trait_page() {
+    let page = rust_html_doc_parse::parse_html(&common::custom_rustdoc_page(
+        "rustdoc trait",
+        "Trait <a href=\"index.html\">foo</a>::<a class=\"trait\" href=\"#\">Source</a>",
+        concat!(
+            "<h2 id=\"associated-types\" class=\"small-section-header\">Associated Types</h2>",
+            "<div class=\"methods\"><details class=\"rustdoc-toggle\" open><summary>",
+            "<div id=\"associatedtype.Item\" class=\"method has-srclink\">",
+            "<h4 class=\"code-header\">type <a href=\"#associatedtype.Item\" class=\"associatedtype\">Item</a></h4>",
+            "</div></summary><div class=\"docblock\"><p>The produced item.</p></div></details></div>",
+            "<h2 id=\"required-methods\" class=\"small-section-header\">Required methods</h2>",
+            "<div class=\"methods\"><details class=\"rustdoc-toggle\" open><summary>",
+            "<div id=\"tymethod.next\" class=\"method has-srclink\">",
+            "<div class=\"rightside\"><a class=\"srclink\" href=\"../src/foo/lib.rs.html#5\">source</a></div>",
+            "<h4 class=\"code-header\">fn <a href=\"#tymethod.next\" class=\"fnname\">next</a>(&amp;mut self) -&gt; Self::Item</h4>",
+            "</div></summary><div class=\"docblock\"><p>Produces the next item.</p><p>Never panics.</p></div></details></div>",
+            "<h2 id=\"provided-methods\" class=\"small-section-header\">Provided methods</h2>",
+            "<div class=\"methods\"><details class=\"rustdoc-toggle\" open><summary>",
+            "<div id=\"method.skip\" class=\"method has-srclink\">",
+            "<h4 class=\"code-header\">fn <a href=\"#method.skip\" class=\"fnname\">skip</a>(&amp;mut self)</h4>",
+            "</div></summary><div class=\"item-info\"><div class=\"stab portability\">",
+            "Available on <strong>crate feature <code>std</code></strong> only.</div></div>",
+            "<div class=\"docblock\"><p>Skips an item.</p></div></details></div>",
+            "<h2 id=\"implementors\" class=\"small-section-header\">Implementors</h2>",
+            "<div id=\"implementors-list\"><section id=\"impl-Source\" class=\"impl\">",
+            "<h3 class=\"code-header\">impl Source for Bytes</h3></section></div>",
+        ),
+    ))
+    .unwrap();
+
+    assert_eq!(
+        page.trait_items
+            .iter()
+            .map(|i| (i.kind.clone(), i.name.as_str(), i.is_required()))
+            .collect::<Vec<_>>(),
+        vec![
+            (AssociatedItemKind::Type, "Item", false),
+            (AssociatedItemKind::Method, "next", true),
+            (AssociatedItemKind::Method, "skip", false),
+        ]
+    );
+
+    let next = page.associated_item("#tymethod.next").unwrap();
+    assert_eq!(next.summary().as_deref(), Some("Produces the next item."));
+    assert_eq!(next.source.as_deref(), Some("../src/foo/lib.rs.html#5"));
+    assert_eq!(
+        next.signature,
+        vec![
+            TextAtomic::simple(&"fn "),
+            TextAtomic::simple(&"next").with_some_url(&"#tymethod.next"),
+            TextAtomic::simple(&"(&mut self) -> Self::Item"),
+        ]
+    );
+
+    let skip = page.associated_item("method.skip").unwrap();
+    assert_eq!(
+        skip.stability.portability.as_deref(),
+        Some("Available on crate feature std only.")
+    );
+    assert_eq!(skip.summary().as_deref(), Some("Skips an item."));
+
+    // The implementors are neither items of the trait
+    // nor implementations of it.
+    assert_eq!(page.associated_items().len(), 3);
+    assert!(page.implementations.is_empty());
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{BlockContainer, Language, TextAtomic, TextAtomicBuilder};
+
+fn parse_docblock(docblock: &str) -> Vec<BlockContainer> {
+    let html = common::rustdoc_page(&format!("<div class=\"docblock\">{}</div>", docblock));
+    let mut page = rust_html_doc_parse::parse_html(&html).unwrap();
+    // Skip the title
+    page.content.0.remove(0);
+    page.content.0
+}
+
+fn paragraph(text: &str) -> BlockContainer {
+    BlockContainer::Paragraph(vec![TextAtomic::simple(&text)])
+}
+
+#[test]
+fn unordered_list() {
+    assert_eq!(
+        parse_docblock("<ul><li>one</li><li><code>two</code></li></ul>"),
+        vec![BlockContainer::BulletPoints {
+            points: vec![
+                vec![paragraph("one")],
+                vec![BlockContainer::Paragraph(vec![TextAtomicBuilder::new(
+                    &"two"
+                )
+                .code(true)
+                .build()])],
+            ],
+            enumerated: false,
+            start: None,
+        }]
+    );
+}
+
+#[test]
+fn ordered_list_with_start() {
+    assert_eq!(
+        parse_docblock("<ol start=\"3\"><li>three</li><li>four</li></ol><ol><li>one</li></ol>"),
+        vec![
+            BlockContainer::BulletPoints {
+                points: vec![vec![paragraph("three")], vec![paragraph("four")]],
+                enumerated: true,
+                start: Some(3),
+            },
+            BlockContainer::BulletPoints {
+                points: vec![vec![paragraph("one")]],
+                enumerated: true,
+                start: Some(1),
+            },
+        ]
+    );
+}
+
+#[test]
+fn nested_lists_with_blocks() {
+    assert_eq!(
+        parse_docblock(
+            "<ul><li><p>Loose item</p><pre class=\"language-toml\"><code>a = 1</code></pre></li>\
+            <li>Outer<ol><li>Inner<ul><li>Innermost</li></ul></li></ol></li></ul>"
+        ),
+        vec![BlockContainer::BulletPoints {
+            points: vec![
+                vec![
+                    paragraph("Loose item"),
+                    BlockContainer::Code {
+                        code: "a = 1".to_string(),
+                        language: Some(Language::Toml),
+                    },
+                ],
+                vec![
+                    paragraph("Outer"),
+                    BlockContainer::BulletPoints {
+                        points: vec![vec![
+                            paragraph("Inner"),
+                            BlockContainer::BulletPoints {
+                                points: vec![vec![paragraph("Innermost")]],
+                                enumerated: false,
+                                start: None,
+                            },
+                        ]],
+                        enumerated: true,
+                        start: Some(1),
+                    },
+                ],
+            ],
+            enumerated: false,
+            start: None,
+        }]
+    );
+}
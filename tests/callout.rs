@@ -0,0 +1,111 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{BlockContainer, CalloutKind, TextAtomic, TextAtomicBuilder};
+
+fn parse_main(main_content: &str) -> Vec<BlockContainer> {
+    let html = common::rustdoc_page(main_content);
+    let mut page = rust_html_doc_parse::parse_html(&html).unwrap();
+    // Skip the title
+    page.content.0.remove(0);
+    page.content.0
+}
+
+fn callout(kind: CalloutKind, atomics: Vec<TextAtomic>) -> BlockContainer {
+    BlockContainer::Callout {
+        kind,
+        content: vec![BlockContainer::Paragraph(atomics)],
+    }
+}
+
+#[test]
+fn stability_banners() {
+    assert_eq!(
+        parse_main(
+            "<div class=\"item-info\">\
+            <div class=\"stab deprecated\"><span class=\"emoji\">👎</span>\
+            <span>Deprecated since 0.8.0: use <code>gen_range</code> instead</span></div>\
+            <div class=\"stab unstable\"><span class=\"emoji\">🔬</span>\
+            <span>This is a nightly-only experimental API. (<code>error_generic_member_access</code>)</span></div>\
+            <div class=\"stab portability\">Available on <strong>crate feature <code>std</code></strong> only.</div>\
+            </div>\
+            <div class=\"docblock\"><p>Docs.</p></div>\
+            <div class=\"item-info\"><span class=\"stab empty-impl\">This impl is empty.</span></div>"
+        ),
+        vec![
+            callout(
+                CalloutKind::Deprecated,
+                vec![
+                    TextAtomic::simple(&"Deprecated since 0.8.0: use "),
+                    TextAtomicBuilder::new(&"gen_range").code(true).build(),
+                    TextAtomic::simple(&" instead"),
+                ]
+            ),
+            callout(
+                CalloutKind::Unstable,
+                vec![
+                    TextAtomic::simple(&"This is a nightly-only experimental API. ("),
+                    TextAtomicBuilder::new(&"error_generic_member_access")
+                        .code(true)
+                        .build(),
+                    TextAtomic::simple(&")"),
+                ]
+            ),
+            callout(
+                CalloutKind::Portability,
+                vec![
+                    TextAtomic::simple(&"Available on "),
+                    TextAtomicBuilder::new(&"crate feature ").bold(true).build(),
+                    TextAtomicBuilder::new(&"std").bold(true).code(true).build(),
+                    TextAtomic::simple(&" only."),
+                ]
+            ),
+            BlockContainer::Paragraph(vec![TextAtomic::simple(&"Docs.")]),
+            callout(
+                CalloutKind::EmptyImpl,
+                vec![TextAtomic::simple(&"This impl is empty.")]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn warning_with_blocks() {
+    assert_eq!(
+        parse_main(
+            "<div class=\"docblock\"><div class=\"warning\"><p>Do not use this.</p>\
+            <ul><li>ever</li></ul></div><div class=\"note\">Noted.</div></div>"
+        ),
+        vec![
+            BlockContainer::Callout {
+                kind: CalloutKind::Warning,
+                content: vec![
+                    BlockContainer::Paragraph(vec![TextAtomic::simple(&"Do not use this.")]),
+                    BlockContainer::BulletPoints {
+                        points: vec![vec![BlockContainer::Paragraph(vec![TextAtomic::simple(
+                            &"ever"
+                        )])]],
+                        enumerated: false,
+                        start: None,
+                    },
+                ],
+            },
+            callout(CalloutKind::Note, vec![TextAtomic::simple(&"Noted.")]),
+        ]
+    );
+}
+
+#[test]
+fn inline_badges_stay_in_the_sentence() {
+    assert_eq!(
+        parse_main(
+            "<div class=\"docblock\"><p>Before <span class=\"stab portability\">\
+            <code>unix</code></span> after</p></div>"
+        ),
+        vec![BlockContainer::Paragraph(vec![
+            TextAtomic::simple(&"Before "),
+            TextAtomicBuilder::new(&"unix").code(true).build(),
+            TextAtomic::simple(&" after"),
+        ])]
+    );
+}
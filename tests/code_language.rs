@@ -0,0 +1,52 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{BlockContainer, Language};
+
+fn code_blocks(main_content: &str) -> Vec<(String, Option<Language>)> {
+    let page = rust_html_doc_parse::parse_html(&common::rustdoc_page(main_content)).unwrap();
+    page.content
+        .0
+        .into_iter()
+        .filter_map(|b| match b {
+            BlockContainer::Code { code, language } => Some((code, language)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn language_from_classes() {
+    let blocks = code_blocks(concat!(
+        "<div class=\"docblock item-decl\"><pre><code>pub struct Foo;</code></pre></div>",
+        "<details class=\"rustdoc-toggle top-doc\" open><div class=\"docblock\">",
+        "<div class=\"example-wrap\"><pre class=\"rust rust-example-rendered\"><code>",
+        "<span class=\"kw\">let</span> x = 1;</code></pre></div>",
+        "<div class=\"example-wrap\"><pre class=\"language-toml\"><code>a = 1</code></pre></div>",
+        "<pre><code class=\"language-sh\">cargo doc</code></pre>",
+        "<div class=\"example-wrap\"><pre class=\"language-text\"><code>plain</code></pre></div>",
+        "</div></details>",
+    ));
+    assert_eq!(
+        blocks,
+        vec![
+            ("pub struct Foo;".to_string(), Some(Language::Rust)),
+            ("let x = 1;".to_string(), Some(Language::Rust)),
+            ("a = 1".to_string(), Some(Language::Toml)),
+            ("cargo doc".to_string(), Some(Language::Bash)),
+            ("plain".to_string(), None),
+        ]
+    );
+}
+
+#[test]
+fn pre_without_code() {
+    let blocks = code_blocks(
+        "<div class=\"docblock\"><pre class=\"rust rust-example-rendered\">\
+        <span class=\"kw\">fn</span> main() {}</pre></div>",
+    );
+    assert_eq!(
+        blocks,
+        vec![("fn main() {}".to_string(), Some(Language::Rust))]
+    );
+}
@@ -0,0 +1,24 @@
+// Minimal rustdoc page skeleton,
+// so single features can be tested
+// without a full docs.rs snapshot.
+#[allow(dead_code)]
+pub fn rustdoc_page(main_content: &str) -> String {
+    custom_rustdoc_page(
+        "rustdoc struct",
+        "Struct <a href=\"index.html\">foo</a>::<a class=\"struct\" href=\"#\">Foo</a>",
+        main_content,
+    )
+}
+
+#[allow(dead_code)]
+pub fn custom_rustdoc_page(body_class: &str, title: &str, main_content: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html lang=\"en\"><head><meta charset=\"utf-8\">\
+        <title>Foo in foo - Rust</title></head>\
+        <body class=\"{}\"><main><div class=\"width-limiter\">\
+        <section id=\"main-content\" class=\"content\">\
+        <div class=\"main-heading\"><h1 class=\"fqn\">{}</h1></div>\
+        {}</section></div></main></body></html>",
+        body_class, title, main_content
+    )
+}
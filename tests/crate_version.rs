@@ -0,0 +1,107 @@
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::CrateVersion;
+
+fn v(s: &str) -> CrateVersion {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parse_and_display() {
+    assert_eq!(
+        v("1.2.3-rc.5+meta"),
+        CrateVersion::Semantic {
+            major: 1,
+            minor: 2,
+            patch: 3,
+            suffix: Some("rc.5".to_string()),
+            build: Some("meta".to_string()),
+        }
+    );
+    assert_eq!(v("0.8.5"), CrateVersion::semantic(0, 8, 5));
+    assert_eq!(v("latest"), CrateVersion::Latest);
+    assert_eq!(v("*"), CrateVersion::Latest);
+    assert_eq!(
+        v("3E1C9A0F2B7D4E6A8C0B1D2E3F4A5B6C7D8E9F00"),
+        CrateVersion::GitCommit {
+            hash: "3e1c9a0f2b7d4e6a8c0b1d2e3f4a5b6c7d8e9f00".to_string()
+        }
+    );
+
+    for s in [
+        "1.2.3-rc.5+meta",
+        "0.7.0-pre.2",
+        "1.0.0+build.5",
+        "0.0.0-0a.0+007",
+        "latest",
+        "3e1c9a0f2b7d4e6a8c0b1d2e3f4a5b6c7d8e9f00",
+    ] {
+        assert_eq!(v(s).to_string(), s);
+    }
+
+    for s in [
+        "",
+        "1.2",
+        "1.2.3.4",
+        "1.x.3",
+        "1.2.3-",
+        "1.2.3-a..b",
+        "1.2.3+",
+        "-1.2.3",
+        "01.2.3",
+        "1.00.3",
+        "1.2.3-01",
+        "1.2.3-rc.007",
+    ] {
+        assert!(
+            s.parse::<CrateVersion>().is_err(),
+            "{:?} should not parse",
+            s
+        );
+    }
+}
+
+#[test]
+fn semver_ordering() {
+    // Example from the semver specification
+    let ordered = [
+        "1.0.0-alpha",
+        "1.0.0-alpha.1",
+        "1.0.0-alpha.beta",
+        "1.0.0-beta",
+        "1.0.0-beta.2",
+        "1.0.0-beta.11",
+        "1.0.0-rc.1",
+        "1.0.0",
+        "1.0.1",
+        "1.1.0",
+        "2.0.0",
+        "10.0.0",
+    ];
+    for pair in ordered.windows(2) {
+        assert!(v(pair[0]) < v(pair[1]), "{} < {}", pair[0], pair[1]);
+    }
+
+    let mut shuffled = vec![
+        v("0.8.5"),
+        v("latest"),
+        v("0.10.0"),
+        v("0.8.5-pre.1"),
+        v("0.9.0"),
+    ];
+    shuffled.sort();
+    assert_eq!(
+        shuffled,
+        vec![
+            v("0.8.5-pre.1"),
+            v("0.8.5"),
+            v("0.9.0"),
+            v("0.10.0"),
+            v("latest")
+        ]
+    );
+
+    // Build metadata only breaks ties
+    assert!(v("1.0.0+a") < v("1.0.0+b"));
+    assert!(v("1.0.0+zzz") < v("1.0.1"));
+    assert!(v("1.0.0-rc.1+zzz") < v("1.0.0"));
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{DocumentedItems, Percentage};
+
+fn with_nav(nav: &str) -> String {
+    common::rustdoc_page("<p>Docs.</p>").replace("<main>", &format!("{}<main>", nav))
+}
+
+#[test]
+fn percentage_from_menu() {
+    let html = with_nav(
+        "<div class=\"nav-container\"><div class=\"pure-menu-children package-details-menu\">\
+        <ul class=\"pure-menu-list\"><li><a href=\"/crate/foo/1.0.0\" class=\"pure-menu-link\">\
+        <b>96.77%</b> of the crate is documented</a></li></ul></div></div>",
+    );
+    let page = rust_html_doc_parse::parse_html(&html).unwrap();
+    assert_eq!(
+        page.meta.documentation_percent,
+        Some(Percentage::from_f32_ratio(0.9677).unwrap())
+    );
+    assert_eq!(page.meta.documented_items, None);
+}
+
+#[test]
+fn item_counts() {
+    let html = with_nav(
+        "<ul class=\"pure-menu-list\"><li class=\"pure-menu-heading\">Coverage</li>\
+        <li class=\"pure-menu-item text-center\"><b>90%</b><br>\
+        <span class=\"documented-info\"><b>45</b> out of <b>50</b> items documented\
+        <b>2</b> out of <b>20</b> items with examples</span></li></ul>",
+    );
+    let page = rust_html_doc_parse::parse_html(&html).unwrap();
+    assert_eq!(
+        page.meta.documented_items,
+        Some(DocumentedItems {
+            documented: 45,
+            total: 50
+        })
+    );
+    assert_eq!(
+        page.meta.documentation_percent,
+        Some(Percentage::from_f32_ratio(0.9).unwrap())
+    );
+}
+
+#[test]
+fn no_coverage_on_local_pages() {
+    let page = rust_html_doc_parse::parse_html(&common::rustdoc_page("<p>Docs.</p>")).unwrap();
+    assert_eq!(page.meta.documentation_percent, None);
+    assert_eq!(page.meta.documented_items, None);
+}
@@ -0,0 +1,256 @@
+#![cfg(feature = "highlight")]
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{
+    AnsiOptions, AnsiOptionsBuilder, BlockContainer, Content, Highlight, Language, StyledLine,
+    TextAtomic, Theme,
+};
+
+// Which parts of the lines got which highlight,
+// found by comparing against the theme.
+fn highlights(code: &str, language: Language) -> Vec<Vec<(Highlight, String)>> {
+    let theme = Theme::dark();
+    let kinds = [
+        Highlight::Plain,
+        Highlight::Keyword,
+        Highlight::Type,
+        Highlight::Function,
+        Highlight::String,
+        Highlight::Number,
+        Highlight::Comment,
+        Highlight::Attribute,
+    ];
+    rust_html_doc_parse::highlight(code, Some(&language), &theme)
+        .into_iter()
+        .map(|line| {
+            line.0
+                .into_iter()
+                .map(|atomic| {
+                    let kind = kinds
+                        .into_iter()
+                        .find(|k| theme.style(*k) == atomic.style)
+                        .unwrap();
+                    (kind, atomic.text)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn h(kind: Highlight, text: &str) -> (Highlight, String) {
+    (kind, text.to_string())
+}
+
+#[test]
+fn rust() {
+    assert_eq!(
+        highlights(
+            "#[derive(Debug)]\nfn max<'a>(x: &'a str) -> u8 {\n    println!(\"{}\", 'c'); // done\n    0x1F\n}",
+            Language::Rust
+        ),
+        vec![
+            vec![h(Highlight::Attribute, "#[derive(Debug)]")],
+            vec![
+                h(Highlight::Keyword, "fn"),
+                h(Highlight::Plain, " "),
+                h(Highlight::Function, "max"),
+                h(Highlight::Plain, "<"),
+                h(Highlight::Type, "'a"),
+                h(Highlight::Plain, ">(x: &"),
+                h(Highlight::Type, "'a"),
+                h(Highlight::Plain, " "),
+                h(Highlight::Type, "str"),
+                h(Highlight::Plain, ") -> "),
+                h(Highlight::Type, "u8"),
+                h(Highlight::Plain, " {"),
+            ],
+            vec![
+                h(Highlight::Plain, "    "),
+                h(Highlight::Function, "println!"),
+                h(Highlight::Plain, "("),
+                h(Highlight::String, "\"{}\""),
+                h(Highlight::Plain, ", "),
+                h(Highlight::String, "'c'"),
+                h(Highlight::Plain, "); "),
+                h(Highlight::Comment, "// done"),
+            ],
+            vec![h(Highlight::Plain, "    "), h(Highlight::Number, "0x1F")],
+            vec![h(Highlight::Plain, "}")],
+        ]
+    );
+}
+
+#[test]
+fn toml() {
+    assert_eq!(
+        highlights(
+            "[dependencies]\n# random\nrand = { version = \"0.8\", default-features = false }",
+            Language::Toml
+        ),
+        vec![
+            vec![h(Highlight::Type, "[dependencies]")],
+            vec![h(Highlight::Comment, "# random")],
+            vec![
+                h(Highlight::Attribute, "rand "),
+                h(Highlight::Plain, "= { version = "),
+                h(Highlight::String, "\"0.8\""),
+                h(Highlight::Plain, ", default-features = "),
+                h(Highlight::Keyword, "false"),
+                h(Highlight::Plain, " }"),
+            ],
+        ]
+    );
+}
+
+#[test]
+fn json_and_shell() {
+    assert_eq!(
+        highlights("{\"a\": [1, true]}", Language::Json),
+        vec![vec![
+            h(Highlight::Plain, "{"),
+            h(Highlight::Attribute, "\"a\""),
+            h(Highlight::Plain, ": ["),
+            h(Highlight::Number, "1"),
+            h(Highlight::Plain, ", "),
+            h(Highlight::Keyword, "true"),
+            h(Highlight::Plain, "]}"),
+        ]]
+    );
+    assert_eq!(
+        highlights(
+            "if [ $# -gt 0 ]; then echo \"$1\"; fi # args",
+            Language::Bash
+        ),
+        vec![vec![
+            h(Highlight::Keyword, "if"),
+            h(Highlight::Plain, " [ $# -gt "),
+            h(Highlight::Number, "0"),
+            h(Highlight::Plain, " ]; "),
+            h(Highlight::Keyword, "then"),
+            h(Highlight::Plain, " echo "),
+            h(Highlight::String, "\"$1\""),
+            h(Highlight::Plain, "; "),
+            h(Highlight::Keyword, "fi"),
+            h(Highlight::Plain, " "),
+            h(Highlight::Comment, "# args"),
+        ]]
+    );
+}
+
+#[test]
+fn markup() {
+    assert_eq!(
+        highlights("<a href=\"x\">link</a><!-- c -->", Language::Html),
+        vec![vec![
+            h(Highlight::Plain, "<"),
+            h(Highlight::Keyword, "a"),
+            h(Highlight::Plain, " "),
+            h(Highlight::Attribute, "href"),
+            h(Highlight::Plain, "="),
+            h(Highlight::String, "\"x\""),
+            h(Highlight::Plain, ">link</"),
+            h(Highlight::Keyword, "a"),
+            h(Highlight::Plain, ">"),
+            h(Highlight::Comment, "<!-- c -->"),
+        ]]
+    );
+}
+
+#[test]
+fn every_language_keeps_the_code() {
+    let code = "x = \"a # b\" // 1\n\n  'y' -- z";
+    for language in [
+        Language::Bash,
+        Language::C,
+        Language::Cpp,
+        Language::Go,
+        Language::Html,
+        Language::Ini,
+        Language::Java,
+        Language::JavaScript,
+        Language::Json,
+        Language::Kotlin,
+        Language::Lua,
+        Language::Matlab,
+        Language::Perl,
+        Language::Php,
+        Language::Python,
+        Language::R,
+        Language::Ruby,
+        Language::Rust,
+        Language::Sql,
+        Language::Swift,
+        Language::Toml,
+        Language::TypeScript,
+        Language::Xml,
+        Language::Yaml,
+    ] {
+        let block = BlockContainer::Code {
+            code: code.to_string(),
+            language: Some(language.clone()),
+        };
+        let lines = block.highlight(&Theme::light()).unwrap();
+        let text = lines
+            .iter()
+            .map(|line| line.text())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(text, code, "{:?}", language);
+    }
+    assert_eq!(
+        BlockContainer::Paragraph(vec![]).highlight(&Theme::dark()),
+        None
+    );
+}
+
+#[test]
+fn ansi_options_highlight_code_blocks() {
+    let content = Content(vec![BlockContainer::Quote(vec![BlockContainer::Code {
+        code: "let x = 1;\n\nfn very_long_name() {}".to_string(),
+        language: Some(Language::Rust),
+    }])]);
+    let theme = Theme::dark();
+    let code = |kind: Highlight, text: &str| TextAtomic {
+        text: text.to_string(),
+        style: theme.style(kind).with_code(true),
+        url: None,
+    };
+    assert_eq!(
+        content.to_highlighted_lines(20, &theme),
+        vec![
+            StyledLine(vec![
+                TextAtomic::simple(&"│ "),
+                TextAtomic::simple(&"    "),
+                code(Highlight::Keyword, "let"),
+                code(Highlight::Plain, " x = "),
+                code(Highlight::Number, "1"),
+                code(Highlight::Plain, ";"),
+            ]),
+            StyledLine(vec![TextAtomic::simple(&"│")]),
+            StyledLine(vec![
+                TextAtomic::simple(&"│ "),
+                TextAtomic::simple(&"    "),
+                code(Highlight::Keyword, "fn"),
+                code(Highlight::Plain, " "),
+                code(Highlight::Function, "very_long_n"),
+            ]),
+            StyledLine(vec![
+                TextAtomic::simple(&"│ "),
+                TextAtomic::simple(&"    "),
+                code(Highlight::Function, "ame"),
+                code(Highlight::Plain, "() {}"),
+            ]),
+        ]
+    );
+
+    let options = AnsiOptionsBuilder::default()
+        .width(20_usize)
+        .theme(theme)
+        .build()
+        .unwrap();
+    let highlighted = content.to_ansi(&options);
+    assert!(highlighted.starts_with("│     \x1b[38;2;198;120;221;48;2;40;44;52mlet\x1b[0m"));
+    assert!(!content
+        .to_ansi(&AnsiOptions::default())
+        .contains("38;2;198;120;221"));
+}
@@ -0,0 +1,133 @@
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{
+    BlockContainer, CalloutKind, Content, Language, Table, TableAlignment, TableCell, TextAtomic,
+    TextAtomicBuilder,
+};
+
+fn text(text: &str) -> TextAtomic {
+    TextAtomic::simple(&text)
+}
+
+fn round_trip(content: &Content) -> Content {
+    rust_html_doc_parse::parse_content_html(&content.to_html()).unwrap()
+}
+
+fn example() -> Content {
+    Content(vec![
+        BlockContainer::Heading2(vec![text("Examples & <notes>")]),
+        BlockContainer::Paragraph(vec![
+            text("Use "),
+            text("it"),
+            TextAtomicBuilder::new(&"Vec<T>")
+                .code(true)
+                .set_url(&"https://doc.rust-lang.org/std/vec/struct.Vec.html?a=1&b=\"2\"")
+                .build(),
+            text(" "),
+            TextAtomicBuilder::new(&"really")
+                .bold(true)
+                .italic(true)
+                .build(),
+            TextAtomicBuilder::new(&"not").strike_through(true).build(),
+            TextAtomicBuilder::new(&"under").underline(true).build(),
+        ]),
+        BlockContainer::Code {
+            code: "let v: Vec<u8> = vec![];\n\n  v.len()".to_string(),
+            language: Some(Language::Rust),
+        },
+        BlockContainer::Code {
+            code: "cargo doc".to_string(),
+            language: None,
+        },
+        BlockContainer::Quote(vec![BlockContainer::Paragraph(vec![text("quoted")])]),
+        BlockContainer::BulletPoints {
+            points: vec![
+                vec![BlockContainer::Paragraph(vec![text("one")])],
+                vec![
+                    BlockContainer::Paragraph(vec![text("two")]),
+                    BlockContainer::BulletPoints {
+                        points: vec![vec![BlockContainer::Paragraph(vec![text("nested")])]],
+                        enumerated: false,
+                        start: None,
+                    },
+                ],
+            ],
+            enumerated: true,
+            start: Some(3),
+        },
+        BlockContainer::Table(Table {
+            header: vec![vec![
+                TableCell::new(vec![text("Name")]).with_header(true),
+                TableCell::new(vec![text("Value")])
+                    .with_header(true)
+                    .with_alignment(Some(TableAlignment::Right)),
+            ]],
+            body: vec![
+                vec![
+                    TableCell::new(vec![TextAtomicBuilder::new(&"a").code(true).build()]),
+                    TableCell::new(vec![text("1")]).with_alignment(Some(TableAlignment::Right)),
+                ],
+                vec![TableCell::new(vec![text("wide")]).with_span(2, 1)],
+            ],
+        }),
+        BlockContainer::Callout {
+            kind: CalloutKind::Deprecated,
+            content: vec![BlockContainer::Paragraph(vec![text(
+                "Deprecated since 0.8.0",
+            )])],
+        },
+        BlockContainer::Callout {
+            kind: CalloutKind::Warning,
+            content: vec![BlockContainer::Paragraph(vec![text("Careful")])],
+        },
+        BlockContainer::Image {
+            url: "https://www.rust-lang.org/logo.png".to_string(),
+            alt: Some("The \"logo\"".to_string()),
+            title: None,
+            width: Some(128),
+            height: None,
+            link: None,
+        },
+    ])
+}
+
+#[test]
+fn semantic_markup() {
+    let content = Content(vec![
+        BlockContainer::Paragraph(vec![
+            text("a < b"),
+            TextAtomicBuilder::new(&"c")
+                .bold(true)
+                .set_url(&"#method.c")
+                .build(),
+        ]),
+        BlockContainer::Code {
+            code: "<T>".to_string(),
+            language: Some(Language::Toml),
+        },
+    ]);
+    assert_eq!(
+        content.to_html(),
+        "<p>a &lt; b<a href=\"#method.c\"><strong>c</strong></a></p>\
+        <pre><code class=\"language-toml\">&lt;T&gt;</code></pre>"
+    );
+}
+
+#[test]
+fn round_trips() {
+    let content = example();
+    assert_eq!(round_trip(&content), content);
+}
+
+#[test]
+fn round_trips_parsed_page() {
+    let docs = include_str!("resources/docs.rs_rand_0.8.5_rand_struct.Error.html");
+    let content = rust_html_doc_parse::parse_html(docs).unwrap().content;
+    let html = content.to_html();
+    assert!(!html.contains("docblock"));
+    assert_eq!(round_trip(&content), content);
+}
+
+#[test]
+fn empty_content() {
+    assert_eq!(round_trip(&Content(vec![])), Content(vec![]));
+}
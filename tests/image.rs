@@ -0,0 +1,142 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{BlockContainer, ParseOptionsBuilder};
+
+fn parse_images(docblock: &str, url: Option<&str>) -> Vec<BlockContainer> {
+    let html = common::rustdoc_page(&format!("<div class=\"docblock\">{}</div>", docblock));
+    let mut options = ParseOptionsBuilder::default();
+    if let Some(url) = url {
+        options.url(url);
+    }
+    let page =
+        rust_html_doc_parse::parse_html_with_options(&html, &options.build().unwrap()).unwrap();
+    page.content
+        .0
+        .into_iter()
+        .filter(|b| matches!(b, BlockContainer::Image { .. }))
+        .collect()
+}
+
+fn image(url: &str, alt: Option<&str>) -> BlockContainer {
+    BlockContainer::Image {
+        url: url.to_string(),
+        alt: alt.map(|s| s.to_string()),
+        title: None,
+        width: None,
+        height: None,
+        link: None,
+    }
+}
+
+#[test]
+fn image_attributes() {
+    assert_eq!(
+        parse_images(
+            "<p><img src=\"https://img.shields.io/crates/v/rand.svg\" alt=\"Crate\" \
+            title=\"Version\" width=\"120\" height=\"20px\"></p><img src=\"logo.png\" alt=\"\">",
+            None
+        ),
+        vec![
+            BlockContainer::Image {
+                url: "https://img.shields.io/crates/v/rand.svg".to_string(),
+                alt: Some("Crate".to_string()),
+                title: Some("Version".to_string()),
+                width: Some(120),
+                height: Some(20),
+                link: None,
+            },
+            image("logo.png", None),
+        ]
+    );
+}
+
+#[test]
+fn srcset_and_picture() {
+    assert_eq!(
+        parse_images(
+            "<img src=\"small.png\" srcset=\"small.png 1x, big.png 2x, medium.png 1.5x\" alt=\"a\">\
+            <picture><source media=\"(prefers-color-scheme: dark)\" srcset=\"dark.png\">\
+            <img src=\"light.png\" alt=\"b\"></picture>\
+            <picture><source srcset=\"narrow.png 480w, wide.png 1080w\"><img alt=\"c\"></picture>",
+            None
+        ),
+        vec![
+            image("big.png", Some("a")),
+            image("light.png", Some("b")),
+            image("wide.png", Some("c")),
+        ]
+    );
+}
+
+#[test]
+fn relative_sources_are_resolved() {
+    assert_eq!(
+        parse_images(
+            "<img src=\"diagram.svg\"><img src=\"../../static/logo.png\">\
+            <img src=\"/-/rustdoc.static/rust-logo.svg\"><img src=\"//example.com/a.png\">\
+            <img src=\"data:image/png;base64,AAAA\">",
+            Some("https://docs.rs/rand/0.8.5/rand/distributions/struct.Uniform.html")
+        ),
+        vec![
+            image(
+                "https://docs.rs/rand/0.8.5/rand/distributions/diagram.svg",
+                None
+            ),
+            image("https://docs.rs/rand/0.8.5/static/logo.png", None),
+            image("https://docs.rs/-/rustdoc.static/rust-logo.svg", None),
+            image("https://example.com/a.png", None),
+            image("data:image/png;base64,AAAA", None),
+        ]
+    );
+    assert_eq!(
+        parse_images(
+            "<img src=\"../img/a.png\">",
+            Some("target/doc/rand/struct.Error.html")
+        ),
+        vec![image("target/doc/img/a.png", None)]
+    );
+    assert_eq!(
+        parse_images(
+            "<img src=\"../img/a.png\"><img src=\"C:\\docs\\b.png\">",
+            Some("C:\\Users\\me\\target\\doc\\rand\\struct.Error.html")
+        ),
+        vec![
+            image("C:/Users/me/target/doc/img/a.png", None),
+            image("C:\\docs\\b.png", None),
+        ]
+    );
+}
+
+#[test]
+fn badge_rows_keep_their_links() {
+    let html = common::rustdoc_page(
+        "<div class=\"docblock\"><p>\
+        <a href=\"https://crates.io/crates/rand\"><img src=\"crate.svg\" alt=\"Crate\"></a> \
+        <a href=\"https://docs.rs/rand\"><img src=\"docs.svg\" alt=\"Docs\"></a>\
+        </p></div>",
+    );
+    let mut content = rust_html_doc_parse::parse_html(&html).unwrap().content;
+    // Without the page heading
+    content.0.remove(0);
+    let badge = |url: &str, alt: &str, link: &str| BlockContainer::Image {
+        url: url.to_string(),
+        alt: Some(alt.to_string()),
+        title: None,
+        width: None,
+        height: None,
+        link: Some(link.to_string()),
+    };
+    assert_eq!(
+        content.0,
+        vec![
+            badge("crate.svg", "Crate", "https://crates.io/crates/rand"),
+            badge("docs.svg", "Docs", "https://docs.rs/rand"),
+        ]
+    );
+    assert_eq!(
+        content.to_markdown(),
+        "[![Crate](crate.svg)](https://crates.io/crates/rand)\n\n\
+        [![Docs](docs.svg)](https://docs.rs/rand)\n"
+    );
+}
@@ -0,0 +1,210 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{
+    AssociatedItemKind, BlockContainer, Content, GenericParam, GenericParamKind, ImplKind,
+    Implementation, TextAtomic, WherePredicate,
+};
+
+fn text(text: &str) -> Vec<TextAtomic> {
+    vec![TextAtomic::simple(&text)]
+}
+
+#[test]
+fn implemented_traits_of_rand_error() {
+    let docs = include_str!("resources/docs.rs_rand_0.8.5_rand_struct.Error.html");
+    let parsed = rust_html_doc_parse::parse_html(docs).unwrap();
+
+    let kinds = parsed
+        .implementations
+        .iter()
+        .map(|i| i.kind.clone())
+        .collect::<Vec<ImplKind>>();
+    assert_eq!(
+        kinds.iter().filter(|k| **k == ImplKind::Inherent).count(),
+        1
+    );
+    assert_eq!(kinds.iter().filter(|k| **k == ImplKind::Trait).count(), 5);
+    assert_eq!(kinds.iter().filter(|k| **k == ImplKind::Auto).count(), 5);
+    assert_eq!(
+        kinds.iter().filter(|k| **k == ImplKind::Blanket).count(),
+        13
+    );
+
+    assert_eq!(
+        parsed.implemented_traits()[..7],
+        [
+            "Debug",
+            "Display",
+            "Error",
+            "From<Error>",
+            "From<NonZeroU32>",
+            "Send",
+            "Sync"
+        ]
+    );
+    assert!(parsed.implements("From"));
+    assert!(parsed.implements("Send"));
+    assert!(!parsed.implements("UnwindSafe"));
+    assert!(!parsed.implements("Clone"));
+
+    let inherent = &parsed.implementations[0];
+    assert_eq!(
+        inherent
+            .items
+            .iter()
+            .map(|i| (i.kind.clone(), i.name.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            (AssociatedItemKind::Constant, "CUSTOM_START"),
+            (AssociatedItemKind::Constant, "INTERNAL_START"),
+            (AssociatedItemKind::Method, "new"),
+            (AssociatedItemKind::Method, "inner"),
+            (AssociatedItemKind::Method, "take_inner"),
+            (AssociatedItemKind::Method, "raw_os_error"),
+            (AssociatedItemKind::Method, "code"),
+        ]
+    );
+
+    let try_from = parsed
+        .implementations
+        .iter()
+        .find(|i| i.trait_text().as_deref() == Some("TryFrom<U>"))
+        .unwrap();
+    assert_eq!(try_from.kind, ImplKind::Blanket);
+    assert_eq!(try_from.self_type, text("T"));
+    assert_eq!(try_from.generics.len(), 2);
+    assert_eq!(try_from.items[0].id, "associatedtype.Error");
+    assert_eq!(try_from.items[0].kind, AssociatedItemKind::Type);
+}
+
+#[test]
+fn newer_layout_with_implementations_list() {
+    let page = rust_html_doc_parse::parse_html(&common::rustdoc_page(concat!(
+        "<h2 id=\"implementations\" class=\"section-header\">Implementations</h2>",
+        "<div id=\"implementations-list\">",
+        "<details class=\"toggle implementors-toggle\" open><summary>",
+        "<section id=\"impl-Foo%3CT%3E\" class=\"impl\">",
+        "<a class=\"src rightside\" href=\"../src/foo/lib.rs.html#10-20\">source</a>",
+        "<a href=\"#impl-Foo%3CT%3E\" class=\"anchor\">§</a>",
+        "<h3 class=\"code-header\">impl&lt;T&gt; <a class=\"struct\" href=\"struct.Foo.html\">Foo</a>&lt;T&gt;",
+        "<div class=\"where\">where\n    T: <a class=\"trait\" href=\"trait.Clone.html\">Clone</a>,</div></h3>",
+        "</section></summary><div class=\"impl-items\">",
+        "<details class=\"toggle method-toggle\" open><summary>",
+        "<section id=\"method.get\" class=\"method\">",
+        "<a class=\"src rightside\" href=\"../src/foo/lib.rs.html#12\">source</a>",
+        "<h4 class=\"code-header\">pub fn <a href=\"#method.get\" class=\"fn\">get</a>(&amp;self) -&gt; T</h4>",
+        "</section></summary><div class=\"docblock\"><p>Returns the value.</p></div></details>",
+        "</div></details></div>",
+        "<h2 id=\"trait-implementations\" class=\"section-header\">Trait Implementations</h2>",
+        "<div id=\"trait-implementations-list\">",
+        "<section id=\"impl-Copy-for-Foo%3CT%3E\" class=\"impl\">",
+        "<h3 class=\"code-header\">impl&lt;T: <a class=\"trait\" href=\"trait.Copy.html\">Copy</a>&gt; ",
+        "<a class=\"trait\" href=\"trait.Copy.html\">Copy</a> for <a class=\"struct\" href=\"struct.Foo.html\">Foo</a>&lt;T&gt;</h3>",
+        "</section></div>",
+        "<h2 id=\"synthetic-implementations\" class=\"section-header\">Auto Trait Implementations</h2>",
+        "<div id=\"synthetic-implementations-list\">",
+        "<section id=\"impl-Send-for-Foo%3CT%3E\" class=\"impl\">",
+        "<h3 class=\"code-header\">unsafe impl&lt;T&gt; !Send for Foo&lt;T&gt;</h3>",
+        "</section></div>",
+    )))
+    .unwrap();
+
+    let foo = vec![
+        TextAtomic::simple(&"Foo").with_some_url(&"struct.Foo.html"),
+        TextAtomic::simple(&"<T>"),
+    ];
+    let inherent = &page.implementations[0];
+    assert_eq!(inherent.kind, ImplKind::Inherent);
+    assert_eq!(inherent.id.as_deref(), Some("impl-Foo%3CT%3E"));
+    assert_eq!(inherent.implemented_trait, None);
+    assert_eq!(inherent.self_type, foo);
+    assert_eq!(
+        inherent.where_clause,
+        vec![WherePredicate {
+            bounded: text("T"),
+            bounds: vec![TextAtomic::simple(&"Clone").with_some_url(&"trait.Clone.html")],
+        }]
+    );
+    assert_eq!(
+        inherent.source.as_deref(),
+        Some("../src/foo/lib.rs.html#10-20")
+    );
+    assert_eq!(inherent.items.len(), 1);
+    assert_eq!(inherent.items[0].name, "get");
+    assert_eq!(
+        inherent.items[0].docs,
+        Some(Content(vec![BlockContainer::Paragraph(text(
+            "Returns the value."
+        ))]))
+    );
+    assert_eq!(
+        inherent.items[0].source.as_deref(),
+        Some("../src/foo/lib.rs.html#12")
+    );
+
+    assert_eq!(
+        page.implementations[1],
+        Implementation {
+            id: Some("impl-Copy-for-Foo%3CT%3E".to_string()),
+            kind: ImplKind::Trait,
+            negative: false,
+            generics: vec![GenericParam {
+                name: "T".to_string(),
+                kind: GenericParamKind::Type,
+                bounds: vec![TextAtomic::simple(&"Copy").with_some_url(&"trait.Copy.html")],
+                default: None,
+            }],
+            implemented_trait: Some(vec![
+                TextAtomic::simple(&"Copy").with_some_url(&"trait.Copy.html")
+            ]),
+            self_type: foo,
+            where_clause: vec![],
+            source: None,
+            items: vec![],
+        }
+    );
+
+    let send = &page.implementations[2];
+    assert_eq!(send.kind, ImplKind::Auto);
+    assert!(send.negative);
+    assert_eq!(send.trait_name().as_deref(), Some("Send"));
+    assert_eq!(page.implemented_traits(), vec!["Copy".to_string()]);
+}
+
+#[test]
+fn implementors_on_trait_pages_are_skipped() {
+    let page = rust_html_doc_parse::parse_html(&common::custom_rustdoc_page(
+        "rustdoc trait",
+        "Trait <a href=\"index.html\">foo</a>::<a class=\"trait\" href=\"#\">Foo</a>",
+        concat!(
+            "<h2 id=\"implementations\" class=\"section-header\">Implementations</h2>",
+            "<div id=\"implementations-list\">",
+            "<section id=\"impl-dyn+Foo\" class=\"impl\">",
+            "<h3 class=\"code-header\">impl dyn <a class=\"trait\" href=\"trait.Foo.html\">Foo</a></h3>",
+            "</section></div>",
+            "<h2 id=\"foreign-impls\" class=\"section-header\">Implementations on Foreign Types</h2>",
+            "<details class=\"toggle implementors-toggle\"><summary>",
+            "<section id=\"impl-Foo-for-u8\" class=\"impl\">",
+            "<h3 class=\"code-header\">impl <a class=\"trait\" href=\"trait.Foo.html\">Foo</a> for u8</h3>",
+            "</section></summary></details>",
+            "<h2 id=\"implementors\" class=\"section-header\">Implementors</h2>",
+            "<div id=\"implementors-list\">",
+            "<section id=\"impl-Foo-for-Bar\" class=\"impl\">",
+            "<h3 class=\"code-header\">impl <a class=\"trait\" href=\"trait.Foo.html\">Foo</a> for ",
+            "<a class=\"struct\" href=\"struct.Bar.html\">Bar</a></h3>",
+            "</section></div>",
+            "<h2 id=\"synthetic-implementors\" class=\"section-header\">Auto implementors</h2>",
+            "<div id=\"synthetic-implementors-list\">",
+            "<section id=\"impl-Foo-for-Baz\" class=\"impl\">",
+            "<h3 class=\"code-header\">impl Foo for Baz</h3>",
+            "</section></div>",
+        ),
+    ))
+    .unwrap();
+    assert_eq!(page.implementations.len(), 1);
+    assert_eq!(page.implementations[0].kind, ImplKind::Inherent);
+    assert_eq!(page.implementations[0].id.as_deref(), Some("impl-dyn+Foo"));
+    assert!(!page.implements("Foo"));
+    assert!(page.implemented_traits().is_empty());
+}
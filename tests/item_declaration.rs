@@ -0,0 +1,476 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{
+    Argument, BlockContainer, Content, DeclarationKind, Field, Fields, FieldsStyle,
+    FunctionSignature, GenericParam, GenericParamKind, ItemDeclaration, TextAtomic, TraitItem,
+    Variant, WherePredicate,
+};
+
+fn declaration(main_content: &str) -> ItemDeclaration {
+    rust_html_doc_parse::parse_html(&common::rustdoc_page(main_content))
+        .unwrap()
+        .declaration
+        .unwrap()
+}
+
+fn decl(code: &str) -> String {
+    format!(
+        "<div class=\"docblock item-decl\"><pre class=\"rust\"><code>{}</code></pre></div>",
+        code
+    )
+}
+
+fn text(text: &str) -> Vec<TextAtomic> {
+    vec![TextAtomic::simple(&text)]
+}
+
+fn docs(text: &str) -> Option<Content> {
+    Some(Content(vec![BlockContainer::Paragraph(vec![
+        TextAtomic::simple(&text),
+    ])]))
+}
+
+#[test]
+fn struct_with_generics_fields_and_docs() {
+    let parsed = declaration(&format!(
+        "{}{}",
+        decl(concat!(
+            "#[non_exhaustive]\n",
+            "pub struct Wrapper&lt;'a, T: <a class=\"trait\" href=\"https://doc.rust-lang.org/nightly/core/clone/trait.Clone.html\">Clone</a>, const N: usize = 4&gt; <span class=\"where fmt-newline\">where\n",
+            "    T: 'a,</span>{\n",
+            "    pub items: <a class=\"struct\" href=\"https://doc.rust-lang.org/nightly/alloc/vec/struct.Vec.html\">Vec</a>&lt;&amp;'a T&gt;,\n",
+            "    pub(crate) size: [T; N],\n",
+            "    /* private fields */\n",
+            "}",
+        )),
+        concat!(
+            "<h2 id=\"fields\" class=\"fields small-section-header\">Fields</h2>",
+            "<span id=\"structfield.items\" class=\"structfield small-section-header\">",
+            "<a href=\"#structfield.items\" class=\"anchor field\"></a>",
+            "<code>items: Vec&lt;&amp;'a T&gt;</code></span>",
+            "<div class=\"docblock\"><p>The wrapped items.</p></div>",
+            "<span id=\"structfield.size\" class=\"structfield small-section-header\">",
+            "<code>size: [T; N]</code></span>",
+        )
+    ));
+    assert_eq!(
+        parsed,
+        ItemDeclaration {
+            attributes: vec!["#[non_exhaustive]".to_string()],
+            visibility: Some("pub".to_string()),
+            name: "Wrapper".to_string(),
+            generics: vec![
+                GenericParam {
+                    name: "'a".to_string(),
+                    kind: GenericParamKind::Lifetime,
+                    bounds: vec![],
+                    default: None,
+                },
+                GenericParam {
+                    name: "T".to_string(),
+                    kind: GenericParamKind::Type,
+                    bounds: vec![TextAtomic::simple(&"Clone").with_some_url(
+                        &"https://doc.rust-lang.org/nightly/core/clone/trait.Clone.html"
+                    )],
+                    default: None,
+                },
+                GenericParam {
+                    name: "N".to_string(),
+                    kind: GenericParamKind::Const,
+                    bounds: text("usize"),
+                    default: Some(text("4")),
+                },
+            ],
+            where_clause: vec![WherePredicate {
+                bounded: text("T"),
+                bounds: text("'a"),
+            }],
+            kind: DeclarationKind::Struct(Fields {
+                style: FieldsStyle::Named,
+                fields: vec![
+                    Field {
+                        visibility: Some("pub".to_string()),
+                        name: "items".to_string(),
+                        ty: vec![
+                            TextAtomic::simple(&"Vec").with_some_url(
+                                &"https://doc.rust-lang.org/nightly/alloc/vec/struct.Vec.html"
+                            ),
+                            TextAtomic::simple(&"<&'a T>"),
+                        ],
+                        docs: docs("The wrapped items."),
+                    },
+                    Field {
+                        visibility: Some("pub(crate)".to_string()),
+                        name: "size".to_string(),
+                        ty: text("[T; N]"),
+                        docs: None,
+                    },
+                ],
+                has_private_fields: true,
+            }),
+        }
+    );
+}
+
+#[test]
+fn tuple_and_unit_structs() {
+    let tuple = declaration(&decl(
+        "pub struct Wrapping&lt;T&gt;(pub T, _) <span class=\"where\">where T: Copy</span>;",
+    ));
+    assert_eq!(
+        tuple.kind,
+        DeclarationKind::Struct(Fields {
+            style: FieldsStyle::Tuple,
+            fields: vec![Field {
+                visibility: Some("pub".to_string()),
+                name: "0".to_string(),
+                ty: text("T"),
+                docs: None,
+            }],
+            has_private_fields: true,
+        })
+    );
+    assert_eq!(tuple.where_clause.len(), 1);
+
+    let unit = declaration(&decl("pub struct Marker;"));
+    assert_eq!(unit.name, "Marker");
+    assert_eq!(
+        unit.kind,
+        DeclarationKind::Struct(Fields {
+            style: FieldsStyle::Unit,
+            fields: vec![],
+            has_private_fields: false,
+        })
+    );
+}
+
+#[test]
+fn enum_variants() {
+    let parsed = declaration(&format!(
+        "{}{}",
+        decl(concat!(
+            "pub enum Shape {\n",
+            "    Empty,\n",
+            "    Circle(f64),\n",
+            "    Rect {\n        width: f64,\n        height: f64,\n    },\n",
+            "    Other = 7,\n",
+            "    // some variants omitted\n",
+            "}",
+        )),
+        concat!(
+            "<div id=\"variant.Circle\" class=\"variant small-section-header\">",
+            "<a href=\"#variant.Circle\" class=\"anchor field\"></a><code>Circle(f64)</code></div>",
+            "<div class=\"docblock\"><p>A circle by radius.</p></div>",
+            "<div id=\"variant.Rect\" class=\"variant small-section-header\">",
+            "<code>Rect</code></div>",
+            "<div class=\"sub-variant\" id=\"variant.Rect.fields\"><h4>Fields</h4>",
+            "<div class=\"sub-variant-field\"><span id=\"variant.Rect.field.width\" class=\"variant small-section-header\">",
+            "<code>width: f64</code></span><div class=\"docblock\"><p>Width in meters.</p></div>",
+            "</div></div>",
+        )
+    ));
+    let unit = Fields {
+        style: FieldsStyle::Unit,
+        fields: vec![],
+        has_private_fields: false,
+    };
+    assert_eq!(
+        parsed.kind,
+        DeclarationKind::Enum {
+            variants: vec![
+                Variant {
+                    name: "Empty".to_string(),
+                    fields: unit.clone(),
+                    discriminant: None,
+                    docs: None,
+                },
+                Variant {
+                    name: "Circle".to_string(),
+                    fields: Fields {
+                        style: FieldsStyle::Tuple,
+                        fields: vec![Field {
+                            visibility: None,
+                            name: "0".to_string(),
+                            ty: text("f64"),
+                            docs: None,
+                        }],
+                        has_private_fields: false,
+                    },
+                    discriminant: None,
+                    docs: docs("A circle by radius."),
+                },
+                Variant {
+                    name: "Rect".to_string(),
+                    fields: Fields {
+                        style: FieldsStyle::Named,
+                        fields: vec![
+                            Field {
+                                visibility: None,
+                                name: "width".to_string(),
+                                ty: text("f64"),
+                                docs: docs("Width in meters."),
+                            },
+                            Field {
+                                visibility: None,
+                                name: "height".to_string(),
+                                ty: text("f64"),
+                                docs: None,
+                            },
+                        ],
+                        has_private_fields: false,
+                    },
+                    discriminant: None,
+                    docs: None,
+                },
+                Variant {
+                    name: "Other".to_string(),
+                    fields: unit,
+                    discriminant: Some("7".to_string()),
+                    docs: None,
+                },
+            ],
+            has_hidden_variants: true,
+        }
+    );
+}
+
+#[test]
+fn trait_items() {
+    let parsed = declaration(&decl(concat!(
+        "pub unsafe trait Source: <a class=\"trait\" href=\"trait.Debug.html\">Debug</a> + Send {\n",
+        "    type Item: Clone;\n",
+        "    const SIZE: usize;\n\n",
+        "    // Required method\n",
+        "    fn next(&amp;mut self) -&gt; <a class=\"enum\" href=\"enum.Option.html\">Option</a>&lt;Self::Item&gt;;\n\n",
+        "    // Provided method\n",
+        "    fn count(self) -&gt; usize\n    <span class=\"where\">where\n        Self: Sized</span> { ... }\n",
+        "}",
+    )));
+    let DeclarationKind::Trait {
+        qualifiers,
+        supertraits,
+        items,
+    } = parsed.kind
+    else {
+        panic!("not a trait: {:?}", parsed.kind);
+    };
+    assert_eq!(qualifiers, vec!["unsafe".to_string()]);
+    assert_eq!(
+        supertraits,
+        vec![
+            TextAtomic::simple(&"Debug").with_some_url(&"trait.Debug.html"),
+            TextAtomic::simple(&" + Send"),
+        ]
+    );
+    assert_eq!(
+        items,
+        vec![
+            TraitItem::AssociatedType {
+                name: "Item".to_string(),
+                bounds: text("Clone"),
+                default: None,
+            },
+            TraitItem::AssociatedConstant {
+                name: "SIZE".to_string(),
+                ty: text("usize"),
+                default: None,
+            },
+            TraitItem::Method {
+                signature: FunctionSignature {
+                    qualifiers: vec![],
+                    name: "next".to_string(),
+                    generics: vec![],
+                    arguments: vec![Argument {
+                        name: "self".to_string(),
+                        ty: text("&mut self"),
+                    }],
+                    return_type: Some(vec![
+                        TextAtomic::simple(&"Option").with_some_url(&"enum.Option.html"),
+                        TextAtomic::simple(&"<Self::Item>"),
+                    ]),
+                    where_clause: vec![],
+                },
+                provided: false,
+            },
+            TraitItem::Method {
+                signature: FunctionSignature {
+                    qualifiers: vec![],
+                    name: "count".to_string(),
+                    generics: vec![],
+                    arguments: vec![Argument {
+                        name: "self".to_string(),
+                        ty: text("self"),
+                    }],
+                    return_type: Some(text("usize")),
+                    where_clause: vec![WherePredicate {
+                        bounded: text("Self"),
+                        bounds: text("Sized"),
+                    }],
+                },
+                provided: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn function_signature() {
+    let parsed = declaration(&decl(concat!(
+        "pub const unsafe extern \"C\" fn swap&lt;T&gt;(x: &amp;mut T, (a, b): (u8, u8)) -&gt; ",
+        "<a class=\"type\" href=\"type.Result.html\">Result</a>&lt;T&gt; <span class=\"where\">where\n",
+        "    T: <a class=\"trait\" href=\"trait.Default.html\">Default</a> + 'static</span>",
+    )));
+    let signature = FunctionSignature {
+        qualifiers: vec![
+            "const".to_string(),
+            "unsafe".to_string(),
+            "extern \"C\"".to_string(),
+        ],
+        name: "swap".to_string(),
+        generics: vec![GenericParam {
+            name: "T".to_string(),
+            kind: GenericParamKind::Type,
+            bounds: vec![],
+            default: None,
+        }],
+        arguments: vec![
+            Argument {
+                name: "x".to_string(),
+                ty: text("&mut T"),
+            },
+            Argument {
+                name: "(a, b)".to_string(),
+                ty: text("(u8, u8)"),
+            },
+        ],
+        return_type: Some(vec![
+            TextAtomic::simple(&"Result").with_some_url(&"type.Result.html"),
+            TextAtomic::simple(&"<T>"),
+        ]),
+        where_clause: vec![WherePredicate {
+            bounded: text("T"),
+            bounds: vec![
+                TextAtomic::simple(&"Default").with_some_url(&"trait.Default.html"),
+                TextAtomic::simple(&" + 'static"),
+            ],
+        }],
+    };
+    assert_eq!(parsed.name, "swap");
+    assert_eq!(parsed.generics, signature.generics);
+    assert_eq!(parsed.where_clause, signature.where_clause);
+    assert_eq!(parsed.kind, DeclarationKind::Function(signature));
+}
+
+#[test]
+fn aliases_constants_and_statics() {
+    let alias = declaration(&decl(
+        "pub type Result&lt;T&gt; = <a href=\"enum.Result.html\">Result</a>&lt;T, Error&gt;;",
+    ));
+    assert_eq!(alias.name, "Result");
+    assert_eq!(
+        alias.kind,
+        DeclarationKind::TypeAlias {
+            ty: Some(vec![
+                TextAtomic::simple(&"Result").with_some_url(&"enum.Result.html"),
+                TextAtomic::simple(&"<T, Error>"),
+            ])
+        }
+    );
+
+    let constant = declaration(&decl("pub const MASK: u32 = 1 &lt;&lt; 31;"));
+    assert_eq!(
+        constant.kind,
+        DeclarationKind::Constant {
+            ty: text("u32"),
+            value: Some("1 << 31".to_string()),
+        }
+    );
+
+    let with_static = declaration(&decl("pub static mut COUNTER: usize;"));
+    assert_eq!(with_static.name, "COUNTER");
+    assert_eq!(
+        with_static.kind,
+        DeclarationKind::Static {
+            mutable: true,
+            ty: text("usize"),
+            value: None,
+        }
+    );
+
+    let macro_rules = declaration(&decl("macro_rules! vec {\n    () =&gt; { ... };\n}"));
+    assert_eq!(macro_rules.name, "vec");
+    assert!(matches!(macro_rules.kind, DeclarationKind::Other(_)));
+}
+
+#[test]
+fn no_declaration_on_module_pages() {
+    let page = rust_html_doc_parse::parse_html(&common::rustdoc_page(
+        "<details class=\"rustdoc-toggle top-doc\" open><div class=\"docblock\"><p>Docs</p></div></details>",
+    ))
+    .unwrap();
+    assert_eq!(page.declaration, None);
+}
+
+#[test]
+fn stray_closing_brackets() {
+    let stray = declaration(&decl("pub trait Foo {\n    ) ;\n}"));
+    assert_eq!(stray.name, "Foo");
+    assert!(matches!(
+        stray.kind,
+        DeclarationKind::Trait { ref items, .. } if items.is_empty()
+    ));
+
+    let method = declaration(&decl("pub trait Foo { fn a(x: [u8; 2]]); }"));
+    let DeclarationKind::Trait { items, .. } = method.kind else {
+        panic!("not a trait: {:?}", method.kind);
+    };
+    assert!(matches!(
+        &items[..],
+        [TraitItem::Method { signature, .. }] if signature.name == "a"
+    ));
+
+    let generics = declaration(&decl("pub fn a&lt;T)&gt;()"));
+    assert_eq!(generics.name, "a");
+}
+
+#[test]
+fn function_without_name() {
+    let parsed = declaration(&decl("pub fn (x: u8)"));
+    assert_eq!(parsed.name, "");
+    let DeclarationKind::Function(signature) = parsed.kind else {
+        panic!("not a function: {:?}", parsed.kind);
+    };
+    assert_eq!(signature.name, "");
+    assert_eq!(
+        signature.arguments,
+        vec![Argument {
+            name: "x".to_string(),
+            ty: text("u8"),
+        }]
+    );
+}
+
+#[test]
+fn shifts_and_binding_modifiers() {
+    let parsed = declaration(&decl(
+        "pub fn f(mut x: Vec&lt;Vec&lt;u8&gt;&gt;, ref mut y: u8) -&gt; [u8; { 1 &gt;&gt; 2 }]",
+    ));
+    let DeclarationKind::Function(signature) = parsed.kind else {
+        panic!("not a function: {:?}", parsed.kind);
+    };
+    assert_eq!(
+        signature.arguments,
+        vec![
+            Argument {
+                name: "x".to_string(),
+                ty: text("Vec<Vec<u8>>"),
+            },
+            Argument {
+                name: "y".to_string(),
+                ty: text("u8"),
+            },
+        ]
+    );
+    assert_eq!(signature.return_type, Some(text("[u8; { 1 >> 2 }]")));
+}
@@ -0,0 +1,166 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{
+    BlockContainer, LinkKind, PageLocation, ParseOptionsBuilder, TextAtomic,
+};
+
+const URL: &str = "https://docs.rs/rand/0.8.5/rand/struct.Error.html";
+
+fn title_links(resolve_links: bool) -> Vec<Option<String>> {
+    let docs = include_str!("resources/docs.rs_rand_0.8.5_rand_struct.Error.html");
+    let options = ParseOptionsBuilder::default()
+        .url(URL)
+        .resolve_links(resolve_links)
+        .build()
+        .unwrap();
+    let parsed = rust_html_doc_parse::parse_html_with_options(docs, &options).unwrap();
+    match &parsed.content.0[0] {
+        BlockContainer::Heading1(atomics) => atomics.iter().map(|a| a.url.clone()).collect(),
+        other => panic!("Expected title, got {:?}", other),
+    }
+}
+
+#[test]
+fn links_are_only_resolved_on_request() {
+    assert_eq!(
+        title_links(false),
+        vec![
+            None,
+            Some("index.html".to_string()),
+            None,
+            Some("#".to_string())
+        ]
+    );
+    assert_eq!(
+        title_links(true),
+        vec![
+            None,
+            Some("https://docs.rs/rand/0.8.5/rand/index.html".to_string()),
+            None,
+            Some("https://docs.rs/rand/0.8.5/rand/struct.Error.html#".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn classification() {
+    let location = PageLocation::parse(URL).unwrap();
+    let cases = [
+        ("#method.new", LinkKind::SamePageAnchor),
+        ("struct.Error.html#method.new", LinkKind::SamePageAnchor),
+        ("index.html", LinkKind::SameCrateItem),
+        ("rngs/struct.StdRng.html", LinkKind::SameCrateItem),
+        ("../rand_core/trait.RngCore.html", LinkKind::OtherCrateItem),
+        (
+            "https://docs.rs/getrandom/latest/getrandom/struct.Error.html",
+            LinkKind::OtherCrateItem,
+        ),
+        (
+            "https://doc.rust-lang.org/nightly/std/primitive.u32.html",
+            LinkKind::StdItem,
+        ),
+        (
+            "https://doc.rust-lang.org/core/option/enum.Option.html",
+            LinkKind::StdItem,
+        ),
+        ("../src/rand/lib.rs.html#1-10", LinkKind::SourceView),
+        (
+            "https://rust-random.github.io/rand/src/rand_core/error.rs.html#28-116",
+            LinkKind::SourceView,
+        ),
+        ("https://github.com/rust-random/rand", LinkKind::WebLink),
+        ("https://doc.rust-lang.org/book/", LinkKind::WebLink),
+    ];
+    for (link, expected) in cases {
+        assert_eq!(location.classify_link(link), expected, "{}", link);
+    }
+
+    let local = PageLocation::parse("target/doc/rand/struct.Error.html").unwrap();
+    assert_eq!(
+        local.classify_link("../rand_core/index.html"),
+        LinkKind::OtherCrateItem
+    );
+    assert_eq!(
+        local.classify_link("distributions/index.html"),
+        LinkKind::SameCrateItem
+    );
+    assert_eq!(
+        TextAtomic::simple(&"Error")
+            .with_some_url(&"#")
+            .link_kind(&local),
+        Some(LinkKind::SamePageAnchor)
+    );
+    assert_eq!(TextAtomic::simple(&"Error").link_kind(&local), None);
+}
+
+#[test]
+fn structured_links_are_resolved() {
+    let docs = include_str!("resources/docs.rs_rand_0.8.5_rand_struct.Error.html");
+    let parse = |resolve_links: bool| {
+        let options = ParseOptionsBuilder::default()
+            .url(URL)
+            .resolve_links(resolve_links)
+            .build()
+            .unwrap();
+        rust_html_doc_parse::parse_html_with_options(docs, &options).unwrap()
+    };
+    let anchor = |page: &rust_html_doc_parse::DocuPage| {
+        page.implementations[0].items[0].signature[1].url.clone()
+    };
+
+    let relative = parse(false);
+    assert_eq!(
+        anchor(&relative).as_deref(),
+        Some("#associatedconstant.CUSTOM_START")
+    );
+    let sidebar = relative.sidebar.unwrap();
+    assert_eq!(sidebar.location.unwrap().url, "#");
+    assert_eq!(sidebar.sections[0].url.as_deref(), Some("#implementations"));
+
+    let absolute = parse(true);
+    assert_eq!(
+        anchor(&absolute),
+        Some(format!("{}#associatedconstant.CUSTOM_START", URL))
+    );
+    let sidebar = absolute.sidebar.unwrap();
+    assert_eq!(sidebar.location.unwrap().url, format!("{}#", URL));
+    assert_eq!(
+        sidebar.sections[0].url,
+        Some(format!("{}#implementations", URL))
+    );
+    assert_eq!(
+        sidebar.sections[0].links[0].url,
+        format!("{}#associatedconstant.CUSTOM_START", URL)
+    );
+}
+
+#[test]
+fn listed_items_are_resolved() {
+    let html = common::custom_rustdoc_page(
+        "rustdoc mod crate",
+        "Crate <a class=\"mod\" href=\"#\">rand</a>",
+        concat!(
+            "<h2 id=\"structs\" class=\"section-header\"><a href=\"#structs\">Structs</a></h2>",
+            "<div class=\"item-table\"><div class=\"item-row\">",
+            "<div class=\"item-left module-item\"><a class=\"struct\" href=\"struct.Error.html\">Error</a></div>",
+            "<div class=\"item-right docblock-short\"><p>See <a href=\"trait.Rng.html\">Rng</a></p></div>",
+            "</div></div>",
+        ),
+    );
+    let options = ParseOptionsBuilder::default()
+        .url("https://docs.rs/rand/0.8.5/rand/index.html")
+        .resolve_links(true)
+        .build()
+        .unwrap();
+    let page = rust_html_doc_parse::parse_html_with_options(&html, &options).unwrap();
+    let item = &page.listings[0].items[0];
+    assert_eq!(
+        item.url,
+        "https://docs.rs/rand/0.8.5/rand/struct.Error.html"
+    );
+    assert_eq!(
+        item.summary[1].url.as_deref(),
+        Some("https://docs.rs/rand/0.8.5/rand/trait.Rng.html")
+    );
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{DocsType, ItemListing, ListedItem, TextAtomic, TextAtomicBuilder};
+
+fn listings(main_content: &str) -> Vec<ItemListing> {
+    rust_html_doc_parse::parse_html(&common::custom_rustdoc_page(
+        "rustdoc mod crate",
+        "Crate <a class=\"mod\" href=\"#\">foo</a>",
+        main_content,
+    ))
+    .unwrap()
+    .listings
+}
+
+fn item(name: &str, url: &str, summary: Vec<TextAtomic>) -> ListedItem {
+    ListedItem {
+        name: name.to_string(),
+        url: url.to_string(),
+        summary,
+        tags: vec![],
+    }
+}
+
+fn expected() -> Vec<ItemListing> {
+    vec![
+        ItemListing {
+            kind: DocsType::Module,
+            items: vec![item(
+                "rngs",
+                "rngs/index.html",
+                vec![TextAtomic::simple(&"Random number generators")],
+            )],
+        },
+        ItemListing {
+            kind: DocsType::Struct,
+            items: vec![
+                item(
+                    "Error",
+                    "struct.Error.html",
+                    vec![TextAtomic::simple(
+                        &"Error type of random number generators",
+                    )],
+                ),
+                ListedItem {
+                    tags: vec!["Deprecated".to_string(), "std".to_string()],
+                    ..item(
+                        "OldRng",
+                        "struct.OldRng.html",
+                        vec![
+                            TextAtomic::simple(&"Use "),
+                            TextAtomicBuilder::new(&"Rng")
+                                .code(true)
+                                .set_url(&"trait.Rng.html")
+                                .build(),
+                        ],
+                    )
+                },
+            ],
+        },
+        ItemListing {
+            kind: DocsType::Function,
+            items: vec![item("random", "fn.random.html", vec![])],
+        },
+    ]
+}
+
+#[test]
+fn item_rows() {
+    let parsed = listings(concat!(
+        "<h2 id=\"modules\" class=\"section-header\"><a href=\"#modules\">Modules</a></h2>",
+        "<div class=\"item-table\"><div class=\"item-row\">",
+        "<div class=\"item-left module-item\"><a class=\"mod\" href=\"rngs/index.html\" title=\"rand::rngs mod\">rngs</a></div>",
+        "<div class=\"item-right docblock-short\"><p>Random number generators</p></div>",
+        "</div></div>",
+        "<h2 id=\"structs\" class=\"section-header\"><a href=\"#structs\">Structs</a></h2>",
+        "<div class=\"item-table\"><div class=\"item-row\">",
+        "<div class=\"item-left module-item\"><a class=\"struct\" href=\"struct.Error.html\">Error</a></div>",
+        "<div class=\"item-right docblock-short\"><p>Error type of random number generators</p></div>",
+        "</div><div class=\"item-row\">",
+        "<div class=\"item-left module-item\"><a class=\"struct\" href=\"struct.OldRng.html\">OldRng</a> ",
+        "<span class=\"stab deprecated\" title=\"\">Deprecated</span> ",
+        "<span class=\"stab portability\" title=\"Available on crate feature `std` only\"><code>std</code></span></div>",
+        "<div class=\"item-right docblock-short\"><p>Use <a href=\"trait.Rng.html\"><code>Rng</code></a></p></div>",
+        "</div></div>",
+        "<h2 id=\"functions\" class=\"section-header\"><a href=\"#functions\">Functions</a></h2>",
+        "<div class=\"item-table\"><div class=\"item-row\">",
+        "<div class=\"item-left module-item\"><a class=\"fn\" href=\"fn.random.html\">random</a></div>",
+        "<div class=\"item-right docblock-short\"></div>",
+        "</div></div>",
+    ));
+    assert_eq!(parsed, expected());
+}
+
+#[test]
+fn list_items() {
+    let parsed = listings(concat!(
+        "<h2 id=\"modules\" class=\"section-header\">Modules</h2>",
+        "<ul class=\"item-table\"><li><div class=\"item-name\">",
+        "<a class=\"mod\" href=\"rngs/index.html\" title=\"mod rand::rngs\">rngs</a></div>",
+        "<div class=\"desc docblock-short\">Random number generators</div></li></ul>",
+        "<h2 id=\"structs\" class=\"section-header\">Structs</h2>",
+        "<ul class=\"item-table\"><li><div class=\"item-name\">",
+        "<a class=\"struct\" href=\"struct.Error.html\">Error</a></div>",
+        "<div class=\"desc docblock-short\">Error type of random number generators</div></li>",
+        "<li><div class=\"item-name\"><a class=\"struct\" href=\"struct.OldRng.html\">OldRng</a>",
+        "<span class=\"stab deprecated\">Deprecated</span><span class=\"stab portability\"><code>std</code></span></div>",
+        "<div class=\"desc docblock-short\">Use <a href=\"trait.Rng.html\"><code>Rng</code></a></div></li></ul>",
+        "<h2 id=\"functions\" class=\"section-header\">Functions</h2>",
+        "<ul class=\"item-table\"><li><div class=\"item-name\">",
+        "<a class=\"fn\" href=\"fn.random.html\">random</a></div></li></ul>",
+    ));
+    assert_eq!(parsed, expected());
+}
+
+#[test]
+fn definition_lists_and_tables() {
+    let parsed = listings(concat!(
+        "<dl class=\"item-table\"><dt><a class=\"mod\" href=\"rngs/index.html\">rngs</a></dt>",
+        "<dd>Random number generators</dd></dl>",
+        "<table><tr class=\"module-item\"><td><a class=\"fn\" href=\"fn.random.html\">random</a></td>",
+        "<td class=\"docblock-short\"></td></tr></table>",
+    ));
+    let expected = expected();
+    assert_eq!(parsed, vec![expected[0].clone(), expected[2].clone()]);
+}
+
+#[test]
+fn no_listings_on_item_pages() {
+    let docs = include_str!("resources/docs.rs_rand_0.8.5_rand_struct.Error.html");
+    assert_eq!(
+        rust_html_doc_parse::parse_html(docs).unwrap().listings,
+        vec![]
+    );
+}
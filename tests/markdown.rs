@@ -0,0 +1,200 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{
+    BlockContainer, CalloutKind, Content, Language, ParseOptionsBuilder, Table, TableAlignment,
+    TableCell, TextAtomic, TextAtomicBuilder,
+};
+
+fn text(text: &str) -> TextAtomic {
+    TextAtomic::simple(&text)
+}
+
+fn paragraph(atomics: Vec<TextAtomic>) -> BlockContainer {
+    BlockContainer::Paragraph(atomics)
+}
+
+#[test]
+fn inline_styles_and_links() {
+    let content = Content(vec![paragraph(vec![
+        text("Use "),
+        TextAtomicBuilder::new(&"Rng ").bold(true).build(),
+        TextAtomicBuilder::new(&"gen")
+            .code(true)
+            .set_url(&"trait.Rng.html#method.gen")
+            .build(),
+        TextAtomicBuilder::new(&" now")
+            .italic(true)
+            .set_url(&"trait.Rng.html#method.gen")
+            .build(),
+        text(" or "),
+        TextAtomicBuilder::new(&"never")
+            .strike_through(true)
+            .build(),
+        text(", *not* [this] &lt;"),
+    ])]);
+    assert_eq!(
+        content.to_markdown(),
+        "Use **Rng** [`gen` *now*](trait.Rng.html#method.gen) or ~~never~~, \\*not\\* \\[this\\] \\&lt;\n"
+    );
+}
+
+#[test]
+fn exclamation_mark_before_link() {
+    let content = Content(vec![paragraph(vec![
+        text("Wow!"),
+        TextAtomicBuilder::new(&"link").set_url(&"http://x").build(),
+        text(" Wow!"),
+    ])]);
+    assert_eq!(content.to_markdown(), "Wow\\![link](http://x) Wow!\n");
+}
+
+#[test]
+fn code_spans_and_blocks() {
+    assert_eq!(
+        TextAtomicBuilder::new(&"a `b`")
+            .code(true)
+            .build()
+            .to_markdown(),
+        "`` a `b` ``"
+    );
+    let code = BlockContainer::Code {
+        code: "/// ```\n/// x\n/// ```\nfn x() {}\n".to_string(),
+        language: Some(Language::Rust),
+    };
+    assert_eq!(
+        code.to_markdown(),
+        "````rust\n/// ```\n/// x\n/// ```\nfn x() {}\n````"
+    );
+}
+
+#[test]
+fn headings_and_line_starts() {
+    let content = Content(vec![
+        BlockContainer::Heading2(vec![text("Examples")]),
+        paragraph(vec![text("# not a heading")]),
+        paragraph(vec![text("2023. A year")]),
+    ]);
+    assert_eq!(
+        content.to_markdown(),
+        "## Examples\n\n\\# not a heading\n\n2023\\. A year\n"
+    );
+}
+
+#[test]
+fn nested_lists() {
+    let content = Content(vec![BlockContainer::BulletPoints {
+        points: vec![
+            vec![paragraph(vec![text("one")])],
+            vec![
+                paragraph(vec![text("two")]),
+                BlockContainer::BulletPoints {
+                    points: vec![
+                        vec![paragraph(vec![text("a")])],
+                        vec![paragraph(vec![text("b")])],
+                    ],
+                    enumerated: false,
+                    start: None,
+                },
+            ],
+        ],
+        enumerated: true,
+        start: Some(9),
+    }]);
+    assert_eq!(
+        content.to_markdown(),
+        "9. one\n\n10. two\n\n    - a\n    - b\n"
+    );
+}
+
+#[test]
+fn tables() {
+    let cell = |t: &str| TableCell::new(vec![text(t)]);
+    let table = BlockContainer::Table(Table {
+        header: vec![vec![
+            cell("Name").with_alignment(Some(TableAlignment::Left)),
+            cell("Value").with_alignment(Some(TableAlignment::Right)),
+            cell("Note"),
+        ]],
+        body: vec![
+            vec![
+                TableCell::new(vec![TextAtomicBuilder::new(&"a|b").code(true).build()]),
+                cell("1"),
+            ],
+            vec![cell("spans").with_span(2, 1), cell("x")],
+        ],
+    });
+    assert_eq!(
+        table.to_markdown(),
+        "| Name | Value | Note |\n\
+        | :--- | ---: | --- |\n\
+        | `a\\|b` | 1 |  |\n\
+        | spans |  | x |"
+    );
+}
+
+#[test]
+fn quotes_callouts_and_images() {
+    let content = Content(vec![
+        BlockContainer::Quote(vec![
+            paragraph(vec![text("first")]),
+            paragraph(vec![text("second")]),
+        ]),
+        BlockContainer::Callout {
+            kind: CalloutKind::Warning,
+            content: vec![paragraph(vec![text("Careful")])],
+        },
+        BlockContainer::Callout {
+            kind: CalloutKind::Deprecated,
+            content: vec![paragraph(vec![text("Deprecated since 0.8.0")])],
+        },
+        BlockContainer::Image {
+            url: "https://www.rust-lang.org/logos/rust-logo-128x128.png".to_string(),
+            alt: Some("logo".to_string()),
+            title: Some("The \"logo\"".to_string()),
+            width: None,
+            height: None,
+            link: None,
+        },
+    ]);
+    assert_eq!(
+        content.to_markdown(),
+        "> first\n>\n> second\n\n\
+        > [!WARNING]\n> Careful\n\n\
+        > Deprecated since 0.8.0\n\n\
+        ![logo](https://www.rust-lang.org/logos/rust-logo-128x128.png \"The \\\"logo\\\"\")\n"
+    );
+}
+
+#[test]
+fn page_front_matter() {
+    let html = common::rustdoc_page("<div class=\"docblock\"><p>Some <em>docs</em>.</p></div>");
+    let options = ParseOptionsBuilder::default()
+        .url("https://docs.rs/foo/1.0.0/foo/struct.Foo.html")
+        .build()
+        .unwrap();
+    let page = rust_html_doc_parse::parse_html_with_options(&html, &options).unwrap();
+    assert_eq!(
+        page.to_markdown(),
+        "---\n\
+        title: \"Struct foo::Foo\"\n\
+        kind: \"struct\"\n\
+        crate: \"foo\"\n\
+        version: \"1.0.0\"\n\
+        url: \"https://docs.rs/foo/1.0.0/foo/struct.Foo.html\"\n\
+        ---\n\n\
+        # Struct [foo](index.html)::[Foo](#)\n\n\
+        Some *docs*.\n"
+    );
+}
+
+#[test]
+fn crate_front_matter() {
+    let html = common::custom_rustdoc_page(
+        "rustdoc mod crate",
+        "Crate <a class=\"mod\" href=\"#\">foo</a>",
+        "<div class=\"docblock\"><p>Docs.</p></div>",
+    );
+    let page = rust_html_doc_parse::parse_html(&html).unwrap();
+    assert!(page.to_markdown().contains("\nkind: \"crate\"\n"));
+}
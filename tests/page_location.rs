@@ -0,0 +1,115 @@
+mod common;
+
+use std::path::PathBuf;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{CrateVersion, DocsType, DocuSource, PageLocation, ParseOptionsBuilder};
+
+#[test]
+fn docs_rs_item() {
+    let url = "https://docs.rs/serde/1.0.152/serde/de/trait.Deserialize.html#tymethod.deserialize";
+    assert_eq!(
+        PageLocation::parse(url).unwrap(),
+        PageLocation {
+            crate_name: "serde".to_string(),
+            crate_version: CrateVersion::semantic(1, 0, 152),
+            source: DocuSource::DocsRs {
+                url: url.to_string()
+            },
+            platform: None,
+            module_path: vec!["serde".to_string(), "de".to_string()],
+            item_kind: DocsType::Trait,
+            item_name: Some("Deserialize".to_string()),
+        }
+    );
+    assert_eq!(
+        PageLocation::parse(url).unwrap().to_url(),
+        "https://docs.rs/serde/1.0.152/serde/de/trait.Deserialize.html"
+    );
+}
+
+#[test]
+fn docs_rs_crate_roots_and_platforms() {
+    let location = PageLocation::parse("https://docs.rs/rand-core").unwrap();
+    assert_eq!(location.crate_version, CrateVersion::Latest);
+    assert_eq!(location.module_path, vec!["rand_core".to_string()]);
+    assert_eq!(location.item_kind, DocsType::Crate);
+    assert_eq!(location.item_name, None);
+    assert_eq!(
+        location.to_url(),
+        "https://docs.rs/rand-core/latest/rand_core/index.html"
+    );
+
+    let location =
+        PageLocation::parse("https://docs.rs/rand/0.8.5/x86_64-pc-windows-msvc/rand/rngs/")
+            .unwrap();
+    assert_eq!(
+        location.platform,
+        Some("x86_64-pc-windows-msvc".to_string())
+    );
+    assert_eq!(location.item_kind, DocsType::Module);
+    assert_eq!(
+        location.to_url(),
+        "https://docs.rs/rand/0.8.5/x86_64-pc-windows-msvc/rand/rngs/index.html"
+    );
+
+    let location = PageLocation::parse(
+        "https://docs.rs/crate/rand/0.8.5/target-redirect/rand/struct.Error.html",
+    )
+    .unwrap();
+    assert_eq!(location.item_kind, DocsType::Struct);
+    assert_eq!(
+        location.to_url(),
+        "https://docs.rs/rand/0.8.5/rand/struct.Error.html"
+    );
+
+    assert_eq!(
+        PageLocation::parse("https://docs.rs/serde/^1/serde/fn.x.html")
+            .unwrap()
+            .crate_version,
+        CrateVersion::Latest
+    );
+    assert_eq!(
+        PageLocation::parse("https://docs.rs/rand/0.8.5/src/rand/lib.rs.html"),
+        None
+    );
+}
+
+#[test]
+fn local_paths() {
+    let path = "/home/me/project/target/doc/serde/de/trait.Deserialize.html";
+    assert_eq!(
+        PageLocation::parse(path).unwrap(),
+        PageLocation {
+            crate_name: "serde".to_string(),
+            crate_version: CrateVersion::Latest,
+            source: DocuSource::Local {
+                filepath: Box::new(PathBuf::from(path))
+            },
+            platform: None,
+            module_path: vec!["serde".to_string(), "de".to_string()],
+            item_kind: DocsType::Trait,
+            item_name: Some("Deserialize".to_string()),
+        }
+    );
+    assert_eq!(PageLocation::parse(path).unwrap().to_url(), path);
+
+    let location = PageLocation::parse("target/doc/foo/doc/index.html").unwrap();
+    assert_eq!(
+        location.module_path,
+        vec!["foo".to_string(), "doc".to_string()]
+    );
+    assert_eq!(location.to_url(), "target/doc/foo/doc/index.html");
+
+    assert_eq!(PageLocation::parse("README.md"), None);
+}
+
+#[test]
+fn parsed_page_carries_location() {
+    let url = "https://docs.rs/foo/1.0.0/foo/struct.Foo.html";
+    let options = ParseOptionsBuilder::default().url(url).build().unwrap();
+    let page =
+        rust_html_doc_parse::parse_html_with_options(&common::rustdoc_page(""), &options).unwrap();
+    assert_eq!(page.location, PageLocation::parse(url));
+    assert!(page.location.is_some());
+}
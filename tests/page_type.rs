@@ -0,0 +1,182 @@
+mod common;
+
+use rust_html_doc_parse::{DocsType, HtmlParseError, ParseOptionsBuilder};
+
+fn page_type(body_class: &str, title: &str, url: Option<&str>) -> Result<DocsType, HtmlParseError> {
+    let html = common::custom_rustdoc_page(body_class, title, "<p>Docs.</p>");
+    let mut options = ParseOptionsBuilder::default();
+    if let Some(url) = url {
+        options.url(url);
+    }
+    rust_html_doc_parse::parse_html_with_options(&html, &options.build().unwrap())
+        .map(|page| page.meta.page_type)
+}
+
+#[test]
+fn from_body_class() {
+    assert_eq!(
+        page_type("rustdoc enum", "x", None).unwrap(),
+        DocsType::Enum
+    );
+    assert_eq!(
+        page_type("rustdoc trait", "x", None).unwrap(),
+        DocsType::Trait
+    );
+    assert_eq!(
+        page_type("rustdoc mod", "x", None).unwrap(),
+        DocsType::Module
+    );
+    assert_eq!(
+        page_type("rustdoc mod crate", "x", None).unwrap(),
+        DocsType::Crate
+    );
+}
+
+#[test]
+fn from_title() {
+    assert_eq!(
+        page_type("", "Enum <a>foo</a>::<a>Bar</a>", None).unwrap(),
+        DocsType::Enum
+    );
+    assert_eq!(page_type("", "Crate foo", None).unwrap(), DocsType::Crate);
+}
+
+#[test]
+fn from_filename() {
+    assert_eq!(
+        page_type(
+            "",
+            "x",
+            Some("https://docs.rs/foo/1.0.0/foo/trait.Bar.html#method.x")
+        )
+        .unwrap(),
+        DocsType::Trait
+    );
+    assert_eq!(
+        page_type("", "x", Some("target/doc/foo/bar/index.html")).unwrap(),
+        DocsType::Module
+    );
+    assert_eq!(
+        page_type("", "x", Some("C:\\target\\doc\\rand\\struct.X.html")).unwrap(),
+        DocsType::Struct
+    );
+}
+
+#[test]
+fn unknown() {
+    match page_type("rustdoc", "Gadget foo::Bar", None) {
+        Err(HtmlParseError::PageTypeUnknown(observed)) => assert_eq!(observed, "Gadget"),
+        other => panic!("Expected PageTypeUnknown, got {:?}", other),
+    }
+}
+
+#[test]
+fn every_item_kind() {
+    // (body class, title, filename, expected)
+    let kinds = [
+        (
+            "associatedtype",
+            "Associated Type foo::Bar::Item",
+            "associatedtype.Item.html",
+            DocsType::AssociatedType,
+        ),
+        (
+            "attr",
+            "Attribute Macro tokio::main",
+            "attr.main.html",
+            DocsType::AttributeMacro,
+        ),
+        (
+            "constant",
+            "Constant std::f32::consts::PI",
+            "constant.PI.html",
+            DocsType::Constant,
+        ),
+        (
+            "derive",
+            "Derive Macro serde::Serialize",
+            "derive.Serialize.html",
+            DocsType::DeriveMacro,
+        ),
+        (
+            "enum",
+            "Enum std::option::Option",
+            "enum.Option.html",
+            DocsType::Enum,
+        ),
+        (
+            "foreigntype",
+            "Foreign Type foo::Opaque",
+            "foreigntype.Opaque.html",
+            DocsType::ForeignType,
+        ),
+        (
+            "fn",
+            "Function std::mem::swap",
+            "fn.swap.html",
+            DocsType::Function,
+        ),
+        (
+            "keyword",
+            "Keyword async",
+            "keyword.async.html",
+            DocsType::Keyword,
+        ),
+        ("macro", "Macro std::vec", "macro.vec.html", DocsType::Macro),
+        (
+            "primitive",
+            "Primitive Type u32",
+            "primitive.u32.html",
+            DocsType::Primitive,
+        ),
+        (
+            "static",
+            "Static foo::GLOBAL",
+            "static.GLOBAL.html",
+            DocsType::Static,
+        ),
+        (
+            "struct",
+            "Struct rand::Error",
+            "struct.Error.html",
+            DocsType::Struct,
+        ),
+        (
+            "trait",
+            "Trait std::fmt::Debug",
+            "trait.Debug.html",
+            DocsType::Trait,
+        ),
+        (
+            "traitalias",
+            "Trait Alias foo::Both",
+            "traitalias.Both.html",
+            DocsType::TraitAlias,
+        ),
+        (
+            "type",
+            "Type Definition std::io::Result",
+            "type.Result.html",
+            DocsType::TypeAlias,
+        ),
+        (
+            "union",
+            "Union std::mem::MaybeUninit",
+            "union.MaybeUninit.html",
+            DocsType::Union,
+        ),
+    ];
+    for (class, title, filename, expected) in kinds {
+        let url = format!("https://docs.rs/foo/1.0.0/foo/{}", filename);
+        assert_eq!(
+            page_type(&format!("rustdoc {}", class), "x", None).unwrap(),
+            expected
+        );
+        assert_eq!(page_type("", title, None).unwrap(), expected);
+        assert_eq!(page_type("", "x", Some(&url)).unwrap(), expected);
+    }
+    assert_eq!(
+        page_type("", "Type Alias std::io::Result", None).unwrap(),
+        DocsType::TypeAlias
+    );
+}
@@ -0,0 +1,117 @@
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{
+    BlockContainer, CalloutKind, Content, Table, TableAlignment, TableCell, TextAtomic,
+    TextAtomicBuilder,
+};
+
+fn text(text: &str) -> TextAtomic {
+    TextAtomic::simple(&text)
+}
+
+fn link(text: &str, url: &str) -> TextAtomic {
+    TextAtomic::simple(&text).with_some_url(&url)
+}
+
+#[test]
+fn links_become_footnotes() {
+    let content = Content(vec![
+        BlockContainer::Heading2(vec![text("Examples")]),
+        BlockContainer::Paragraph(vec![
+            text("Use "),
+            TextAtomicBuilder::new(&"thread_rng")
+                .code(true)
+                .set_url(&"fn.thread_rng.html")
+                .build(),
+            text(" or the "),
+            link("Rng", "trait.Rng.html"),
+            text(" trait, see "),
+            link("thread_rng", "fn.thread_rng.html"),
+            text(" again."),
+        ]),
+    ]);
+    assert_eq!(
+        content.to_plain_text(30),
+        "Examples\n\
+        \n\
+        Use thread_rng[1] or the\n\
+        Rng[2] trait, see\n\
+        thread_rng[1] again.\n\
+        \n\
+        [1] fn.thread_rng.html\n\
+        [2] trait.Rng.html\n"
+    );
+}
+
+#[test]
+fn ascii_tables_lists_and_images() {
+    let paragraph = |atomics: Vec<TextAtomic>| vec![BlockContainer::Paragraph(atomics)];
+    let cell = |atomics: Vec<TextAtomic>| TableCell::new(atomics);
+    let content = Content(vec![
+        BlockContainer::Table(Table {
+            header: vec![vec![
+                cell(vec![text("Type")]).with_header(true),
+                cell(vec![text("Bits")])
+                    .with_header(true)
+                    .with_alignment(Some(TableAlignment::Right)),
+            ]],
+            body: vec![vec![
+                cell(vec![link("u32", "primitive.u32.html")]),
+                cell(vec![text("32")]).with_alignment(Some(TableAlignment::Right)),
+            ]],
+        }),
+        BlockContainer::BulletPoints {
+            points: vec![paragraph(vec![text("one")]), paragraph(vec![text("two")])],
+            enumerated: false,
+            start: None,
+        },
+        BlockContainer::Quote(paragraph(vec![text("quoted")])),
+        BlockContainer::Callout {
+            kind: CalloutKind::Note,
+            content: paragraph(vec![text("Noted")]),
+        },
+        BlockContainer::Image {
+            url: "logo.png".to_string(),
+            alt: Some("logo".to_string()),
+            title: None,
+            width: None,
+            height: None,
+            link: None,
+        },
+    ]);
+    assert_eq!(
+        content.to_plain_text(80),
+        "+--------+------+\n\
+        | Type   | Bits |\n\
+        +--------+------+\n\
+        | u32[1] |   32 |\n\
+        +--------+------+\n\
+        \n\
+        * one\n\
+        * two\n\
+        \n\
+        > quoted\n\
+        \n\
+        | Note\n\
+        | Noted\n\
+        \n\
+        [image: logo]\n\
+        \n\
+        [1] primitive.u32.html\n"
+    );
+}
+
+#[test]
+fn rand_error_is_deterministic() {
+    let docs = include_str!("resources/docs.rs_rand_0.8.5_rand_struct.Error.html");
+    let content = rust_html_doc_parse::parse_html(docs).unwrap().content;
+    let rendered = content.to_plain_text(72);
+    assert_eq!(rendered, content.to_plain_text(72));
+    // Footnote urls are never broken up
+    assert!(rendered
+        .lines()
+        .filter(|line| !line.starts_with('['))
+        .all(|line| line.chars().count() <= 72));
+    assert!(rendered.starts_with("Struct rand[1]::Error[2]\n"));
+    assert!(rendered.contains("\n\n[1] index.html\n[2] #\n"));
+    assert!(!rendered.contains('\x1b'));
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{BlockContainer, Language, TextAtomic, TextAtomicBuilder};
+
+fn parse_docblock(docblock: &str) -> Vec<BlockContainer> {
+    let html = common::rustdoc_page(&format!("<div class=\"docblock\">{}</div>", docblock));
+    let mut page = rust_html_doc_parse::parse_html(&html).unwrap();
+    // Skip the title
+    page.content.0.remove(0);
+    page.content.0
+}
+
+fn paragraph(text: &str) -> BlockContainer {
+    BlockContainer::Paragraph(vec![TextAtomic::simple(&text)])
+}
+
+#[test]
+fn simple_quote() {
+    assert_eq!(
+        parse_docblock(
+            "<p>RFC 2119 says:</p><blockquote><p>MUST means <em>absolute</em>.</p></blockquote>"
+        ),
+        vec![
+            paragraph("RFC 2119 says:"),
+            BlockContainer::Quote(vec![BlockContainer::Paragraph(vec![
+                TextAtomic::simple(&"MUST means "),
+                TextAtomicBuilder::new(&"absolute").italic(true).build(),
+                TextAtomic::simple(&"."),
+            ])]),
+        ]
+    );
+}
+
+#[test]
+fn nested_quote_with_list_and_code() {
+    assert_eq!(
+        parse_docblock(
+            "<blockquote><p>Outer</p><blockquote>Inner</blockquote>\
+            <ul><li>point</li></ul><pre><code class=\"language-rust\">let x;</code></pre>\
+            </blockquote>"
+        ),
+        vec![BlockContainer::Quote(vec![
+            paragraph("Outer"),
+            BlockContainer::Quote(vec![paragraph("Inner")]),
+            BlockContainer::BulletPoints {
+                points: vec![vec![paragraph("point")]],
+                enumerated: false,
+                start: None,
+            },
+            BlockContainer::Code {
+                code: "let x;".to_string(),
+                language: Some(Language::Rust),
+            },
+        ])]
+    );
+}
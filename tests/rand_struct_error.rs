@@ -1,7 +1,10 @@
 use pretty_assertions::assert_eq;
 use rust_html_doc_parse::{
-    self, BlockContainer, Content, DocsType, DocuPage, DocuPageMeta, References, TextAtomic,
-    TextAtomicBuilder,
+    self, AssociatedItem, AssociatedItemKind, BlockContainer, Content, CrateVersion,
+    DeclarationKind, DependencyKind, DependencyReference, DocsType, DocuPage, DocuPageMeta, Fields,
+    FieldsStyle, ImplKind, Implementation, ItemDeclaration, Language, OwnerReference, Percentage,
+    PlatformReference, References, Sidebar, SidebarLink, SidebarSection, Stability, TextAtomic,
+    TextAtomicBuilder, VersionReference,
 };
 
 #[test]
@@ -14,6 +17,15 @@ fn fail() {
     // Writing down the entire page here sucks.
     // So we limit it to the fist 9 containers.
     parsed.content.0.truncate(9);
+    // Same for the dependencies and versions.
+    let references = &mut parsed.meta.references;
+    references.dependencies.as_mut().unwrap().truncate(2);
+    references.versions.as_mut().unwrap().truncate(2);
+    // And for the impls, only the inherent one with its first item.
+    parsed.implementations.truncate(1);
+    parsed.implementations[0].items.truncate(1);
+    // The sidebar lists the impls again.
+    parsed.sidebar.as_mut().unwrap().sections.truncate(2);
 
     let expected = DocuPage {
         content: Content(vec![
@@ -25,7 +37,7 @@ fn fail() {
             ]),
             BlockContainer::Code {
                 code: "pub struct Error { /* private fields */ }".to_string(),
-                language: None,
+                language: Some(Language::Rust),
             },
             BlockContainer::Paragraph(vec![TextAtomic::simple(
                 &"Error type of random number generators",
@@ -64,18 +76,182 @@ fn fail() {
             ]),
         ]),
         meta: DocuPageMeta {
-            documentation_percent: None, //Some(Percentage::from_f32_ratio(1.).unwrap()),
+            documentation_percent: Some(Percentage::from_f32_ratio(1.).unwrap()),
+            documented_items: None,
             page_type: DocsType::Struct,
             references: References {
-                crates_io: None,
-                dependencies: None,
-                owners: None,
-                platforms: None,
-                repository: None,
-                versions: None,
+                crates_io: Some("https://crates.io/crates/rand".to_string()),
+                dependencies: Some(vec![
+                    DependencyReference {
+                        name: "log".to_string(),
+                        kind: DependencyKind::Normal,
+                        requirement: "^0.4.4".to_string(),
+                        version: Some(CrateVersion::semantic(0, 4, 4)),
+                        url: "https://docs.rs/log/^0.4.4".to_string(),
+                    },
+                    DependencyReference {
+                        name: "packed_simd_2".to_string(),
+                        kind: DependencyKind::Normal,
+                        requirement: "^0.3.7".to_string(),
+                        version: Some(CrateVersion::semantic(0, 3, 7)),
+                        url: "https://docs.rs/packed_simd_2/^0.3.7".to_string(),
+                    },
+                ]),
+                owners: Some(
+                    [
+                        "alexcrichton",
+                        "dhardy",
+                        "github:rust-lang-nursery:libs",
+                        "github:rust-random:maintainers",
+                    ]
+                    .iter()
+                    .map(|name| OwnerReference {
+                        name: name.to_string(),
+                        url: format!("https://crates.io/users/{}", name),
+                    })
+                    .collect(),
+                ),
+                platforms: Some(
+                    [
+                        "x86_64-unknown-linux-gnu",
+                        "i686-unknown-linux-gnu",
+                        "i686-pc-windows-msvc",
+                        "x86_64-apple-darwin",
+                        "x86_64-pc-windows-msvc",
+                    ]
+                    .iter()
+                    .map(|name| PlatformReference {
+                        name: name.to_string(),
+                        url: format!(
+                            "https://docs.rs/crate/rand/0.8.5/target-redirect/{}/rand/struct.Error.html",
+                            name
+                        ),
+                    })
+                    .collect(),
+                ),
+                repository: Some("https://github.com/rust-random/rand".to_string()),
+                versions: Some(vec![
+                    VersionReference {
+                        version: CrateVersion::semantic(0, 8, 5),
+                        url: "https://docs.rs/crate/rand/0.8.5/target-redirect/rand/struct.Error.html"
+                            .to_string(),
+                    },
+                    VersionReference {
+                        version: CrateVersion::semantic(0, 8, 4),
+                        url: "https://docs.rs/crate/rand/0.8.4/target-redirect/rand/struct.Error.html"
+                            .to_string(),
+                    },
+                ]),
             },
             title: "Struct rand::Error".to_string(),
         },
+        declaration: Some(ItemDeclaration {
+            attributes: vec![],
+            visibility: Some("pub".to_string()),
+            name: "Error".to_string(),
+            generics: vec![],
+            where_clause: vec![],
+            kind: DeclarationKind::Struct(Fields {
+                style: FieldsStyle::Named,
+                fields: vec![],
+                has_private_fields: true,
+            }),
+        }),
+        implementations: vec![Implementation {
+            id: Some("impl".to_string()),
+            kind: ImplKind::Inherent,
+            negative: false,
+            generics: vec![],
+            implemented_trait: None,
+            self_type: vec![TextAtomic::simple(&"Error").with_some_url(&"struct.Error.html")],
+            where_clause: vec![],
+            source: Some(
+                "https://rust-random.github.io/rand/src/rand_core/error.rs.html#28-116".to_string(),
+            ),
+            items: vec![AssociatedItem {
+                id: "associatedconstant.CUSTOM_START".to_string(),
+                kind: AssociatedItemKind::Constant,
+                name: "CUSTOM_START".to_string(),
+                signature: vec![
+                    TextAtomic::simple(&"pub const "),
+                    TextAtomic::simple(&"CUSTOM_START")
+                        .with_some_url(&"#associatedconstant.CUSTOM_START"),
+                    TextAtomic::simple(&": "),
+                    TextAtomic::simple(&"u32")
+                        .with_some_url(&"https://doc.rust-lang.org/nightly/std/primitive.u32.html"),
+                ],
+                docs: Some(Content(vec![
+                    BlockContainer::Paragraph(vec![TextAtomic::simple(
+                        &"Codes at or above this point can be used by users to define their own custom errors.",
+                    )]),
+                    BlockContainer::Paragraph(vec![
+                        TextAtomic::simple(&"This has a fixed value of "),
+                        TextAtomicBuilder::new(&"(1 << 31) + (1 << 30) = 0xC000_0000")
+                            .code(true)
+                            .build(),
+                        TextAtomic::simple(
+                            &", therefore the number of values available for custom codes is ",
+                        ),
+                        TextAtomicBuilder::new(&"1 << 30").code(true).build(),
+                        TextAtomic::simple(&"."),
+                    ]),
+                    BlockContainer::Paragraph(vec![
+                        TextAtomic::simple(&"This is identical to "),
+                        TextAtomicBuilder::new(&"getrandom::Error::CUSTOM_START")
+                            .code(true)
+                            .set_url(&"https://docs.rs/getrandom/latest/getrandom/struct.Error.html#associatedconstant.CUSTOM_START")
+                            .build(),
+                        TextAtomic::simple(&"."),
+                    ]),
+                ])),
+                stability: Stability::default(),
+                source: Some(
+                    "https://rust-random.github.io/rand/src/rand_core/error.rs.html#36".to_string(),
+                ),
+            }],
+        }],
+        trait_items: vec![],
+        listings: vec![],
+        sidebar: Some(Sidebar {
+            location: Some(SidebarLink {
+                text: "Error".to_string(),
+                url: "#".to_string(),
+            }),
+            crate_name: None,
+            crate_version: None,
+            parents: vec![SidebarLink {
+                text: "rand".to_string(),
+                url: "index.html".to_string(),
+            }],
+            sections: vec![
+                SidebarSection {
+                    title: Some("Associated Constants".to_string()),
+                    url: Some("#implementations".to_string()),
+                    links: [
+                        ("CUSTOM_START", "#associatedconstant.CUSTOM_START"),
+                        ("INTERNAL_START", "#associatedconstant.INTERNAL_START"),
+                    ]
+                    .iter()
+                    .map(|(text, url)| SidebarLink {
+                        text: text.to_string(),
+                        url: url.to_string(),
+                    })
+                    .collect(),
+                },
+                SidebarSection {
+                    title: Some("Methods".to_string()),
+                    url: Some("#implementations".to_string()),
+                    links: ["code", "inner", "new", "raw_os_error", "take_inner"]
+                        .iter()
+                        .map(|name| SidebarLink {
+                            text: name.to_string(),
+                            url: format!("#method.{}", name),
+                        })
+                        .collect(),
+                },
+            ],
+        }),
+        location: None,
     };
     assert_eq!(parsed.meta, expected.meta);
     assert_eq!(parsed.content, expected.content);
@@ -0,0 +1,94 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{CrateVersion, DependencyKind, DependencyReference, References};
+
+#[test]
+fn local_page_has_no_references() {
+    // Pages built by `cargo doc` lack the docs.rs navigation bar.
+    let html = common::rustdoc_page("<div class=\"docblock\"><p>Docs.</p></div>");
+    let page = rust_html_doc_parse::parse_html(&html).unwrap();
+    assert_eq!(
+        page.meta.references,
+        References {
+            crates_io: None,
+            dependencies: None,
+            owners: None,
+            platforms: None,
+            repository: None,
+            versions: None,
+        }
+    );
+}
+
+#[test]
+fn dependencies_with_version_ranges() {
+    let dependency = |href: &str, label: &str, kind: &str| {
+        format!(
+            "<li class=\"pure-menu-item\"><a href=\"{}\" class=\"pure-menu-link\">\
+            {} <i class=\"dependencies {}\">{}</i></a></li>",
+            href, label, kind, kind
+        )
+    };
+    let html = common::rustdoc_page("<div class=\"docblock\"><p>Docs.</p></div>").replace(
+        "<body class=\"rustdoc struct\">",
+        &format!(
+            "<body class=\"rustdoc struct\"><div class=\"pure-menu-children package-details-menu\">\
+            <ul class=\"pure-menu-list\"><li class=\"pure-menu-heading\">Dependencies</li>{}{}{}{}{}{}</ul></div>",
+            dependency("/log/^0.4.4", "log ^0.4.4", "normal"),
+            dependency("/syn/>=1, <2", "syn &gt;=1, &lt;2", "normal"),
+            dependency("/cc/*", "cc *", "build"),
+            dependency("/serde/~1", "serde ~1", "dev"),
+            dependency("/rand/>0.7", "rand &gt;0.7", "normal"),
+            dependency("/libc/>= 0.2.1", "libc &gt;= 0.2.1", "normal"),
+        ),
+    );
+    let page = rust_html_doc_parse::parse_html(&html).unwrap();
+    assert_eq!(
+        page.meta.references.dependencies,
+        Some(vec![
+            DependencyReference {
+                name: "log".to_string(),
+                kind: DependencyKind::Normal,
+                requirement: "^0.4.4".to_string(),
+                version: Some(CrateVersion::semantic(0, 4, 4)),
+                url: "https://docs.rs/log/^0.4.4".to_string(),
+            },
+            DependencyReference {
+                name: "syn".to_string(),
+                kind: DependencyKind::Normal,
+                requirement: ">=1, <2".to_string(),
+                version: None,
+                url: "https://docs.rs/syn/>=1, <2".to_string(),
+            },
+            DependencyReference {
+                name: "cc".to_string(),
+                kind: DependencyKind::Build,
+                requirement: "*".to_string(),
+                version: Some(CrateVersion::Latest),
+                url: "https://docs.rs/cc/*".to_string(),
+            },
+            DependencyReference {
+                name: "serde".to_string(),
+                kind: DependencyKind::Dev,
+                requirement: "~1".to_string(),
+                version: Some(CrateVersion::semantic(1, 0, 0)),
+                url: "https://docs.rs/serde/~1".to_string(),
+            },
+            DependencyReference {
+                name: "rand".to_string(),
+                kind: DependencyKind::Normal,
+                requirement: ">0.7".to_string(),
+                version: None,
+                url: "https://docs.rs/rand/>0.7".to_string(),
+            },
+            DependencyReference {
+                name: "libc".to_string(),
+                kind: DependencyKind::Normal,
+                requirement: ">= 0.2.1".to_string(),
+                version: Some(CrateVersion::semantic(0, 2, 1)),
+                url: "https://docs.rs/libc/>= 0.2.1".to_string(),
+            },
+        ])
+    );
+}
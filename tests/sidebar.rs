@@ -0,0 +1,99 @@
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{CrateVersion, Sidebar, SidebarLink, SidebarSection};
+
+fn sidebar(sidebar_html: &str) -> Option<Sidebar> {
+    let page = format!(
+        "<!DOCTYPE html><html lang=\"en\"><head><meta charset=\"utf-8\">\
+        <title>rand - Rust</title></head>\
+        <body class=\"rustdoc mod crate\">{}<main><div class=\"width-limiter\">\
+        <section id=\"main-content\" class=\"content\">\
+        <div class=\"main-heading\"><h1 class=\"fqn\">Crate <a class=\"mod\" href=\"#\">rand</a></h1></div>\
+        </section></div></main></body></html>",
+        sidebar_html
+    );
+    rust_html_doc_parse::parse_html(&page).unwrap().sidebar
+}
+
+fn link(text: &str, url: &str) -> SidebarLink {
+    SidebarLink {
+        text: text.to_string(),
+        url: url.to_string(),
+    }
+}
+
+#[test]
+fn older_crate_root() {
+    let parsed = sidebar(concat!(
+        "<nav class=\"sidebar\"><a class=\"sidebar-logo\" href=\"../rand/index.html\"></a>",
+        "<h2 class=\"location\"><a href=\"#\">Crate rand</a></h2>",
+        "<div class=\"sidebar-elems\"><a id=\"all-types\" href=\"all.html\"><p>See all rand's items</p></a>",
+        "<div class=\"block version\"><div class=\"narrow-helper\"></div><p>Version 0.8.5</p></div>",
+        "<div class=\"block items\"><ul>",
+        "<li><a href=\"#modules\">Modules</a></li><li><a href=\"#structs\">Structs</a></li>",
+        "</ul></div>",
+        "<div id=\"sidebar-vars\" data-name=\"rand\" data-ty=\"mod\" data-relpath=\"\"></div></div></nav>",
+    ));
+    assert_eq!(
+        parsed,
+        Some(Sidebar {
+            location: Some(link("Crate rand", "#")),
+            crate_name: Some("rand".to_string()),
+            crate_version: Some(CrateVersion::semantic(0, 8, 5)),
+            parents: vec![],
+            sections: vec![SidebarSection {
+                title: None,
+                url: None,
+                links: vec![link("Modules", "#modules"), link("Structs", "#structs")],
+            }],
+        })
+    );
+}
+
+#[test]
+fn newer_item_page() {
+    let parsed = sidebar(concat!(
+        "<nav class=\"sidebar\"><div class=\"sidebar-crate\">",
+        "<h2><a href=\"../rand/index.html\">rand</a><span class=\"version\">0.9.0-alpha.1</span></h2></div>",
+        "<div class=\"sidebar-elems\"><section id=\"rustdoc-toc\">",
+        "<h2 class=\"location\"><a href=\"#\">ThreadRng</a></h2>",
+        "<h3><a href=\"#trait-implementations\">Trait Implementations</a></h3>",
+        "<ul class=\"block trait-implementation\">",
+        "<li><a href=\"#impl-Clone-for-ThreadRng\">Clone</a></li>",
+        "<li><a href=\"#impl-RngCore-for-ThreadRng\">RngCore</a></li></ul>",
+        "<h3><a href=\"#synthetic-implementations\">Auto Trait Implementations</a></h3>",
+        "<ul class=\"block synthetic-implementation\">",
+        "<li><a href=\"#impl-Send-for-ThreadRng\">!Send</a></li></ul>",
+        "</section><div id=\"rustdoc-modnav\">",
+        "<h2><a href=\"index.html\">In rand::<wbr>rngs</a></h2></div></div></nav>",
+    ))
+    .unwrap();
+
+    assert_eq!(parsed.location, Some(link("ThreadRng", "#")));
+    assert_eq!(parsed.crate_name.as_deref(), Some("rand"));
+    assert_eq!(
+        parsed.crate_version,
+        Some("0.9.0-alpha.1".parse::<CrateVersion>().unwrap())
+    );
+    assert_eq!(parsed.parents, vec![link("rand::rngs", "index.html")]);
+    assert_eq!(parsed.sections.len(), 2);
+    assert_eq!(
+        parsed.section("Trait Implementations"),
+        Some(&SidebarSection {
+            title: Some("Trait Implementations".to_string()),
+            url: Some("#trait-implementations".to_string()),
+            links: vec![
+                link("Clone", "#impl-Clone-for-ThreadRng"),
+                link("RngCore", "#impl-RngCore-for-ThreadRng"),
+            ],
+        })
+    );
+    assert_eq!(
+        parsed.section("Auto Trait Implementations").unwrap().links,
+        vec![link("!Send", "#impl-Send-for-ThreadRng")]
+    );
+}
+
+#[test]
+fn no_sidebar() {
+    assert_eq!(sidebar(""), None);
+}
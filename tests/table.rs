@@ -0,0 +1,89 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{
+    BlockContainer, Table, TableAlignment, TableCell, TextAtomic, TextAtomicBuilder,
+};
+
+fn parse_table(table: &str) -> Table {
+    let html = common::rustdoc_page(&format!("<div class=\"docblock\">{}</div>", table));
+    let page = rust_html_doc_parse::parse_html(&html).unwrap();
+    page.content
+        .0
+        .into_iter()
+        .find_map(|b| match b {
+            BlockContainer::Table(t) => Some(t),
+            _ => None,
+        })
+        .unwrap()
+}
+
+fn cell(text: &str) -> TableCell {
+    TableCell::new(vec![TextAtomic::simple(&text)])
+}
+
+#[test]
+fn markdown_table_with_alignment() {
+    // As rendered by rustdoc for
+    // | Name | Value |
+    // |:-----|------:|
+    // | `a`  | 1     |
+    let table = parse_table(
+        "<table><thead><tr><th style=\"text-align: left\">Name</th>\
+        <th style=\"text-align: right\">Value</th></tr></thead>\
+        <tbody><tr><td style=\"text-align: left\"><code>a</code> or <em>b</em></td>\
+        <td style=\"text-align: right\">1</td></tr></tbody></table>",
+    );
+    assert_eq!(
+        table,
+        Table {
+            header: vec![vec![
+                cell("Name")
+                    .with_header(true)
+                    .with_alignment(Some(TableAlignment::Left)),
+                cell("Value")
+                    .with_header(true)
+                    .with_alignment(Some(TableAlignment::Right)),
+            ]],
+            body: vec![vec![
+                TableCell::new(vec![
+                    TextAtomicBuilder::new(&"a").code(true).build(),
+                    TextAtomic::simple(&" or "),
+                    TextAtomicBuilder::new(&"b").italic(true).build(),
+                ])
+                .with_alignment(Some(TableAlignment::Left)),
+                cell("1").with_alignment(Some(TableAlignment::Right)),
+            ]],
+        }
+    );
+}
+
+#[test]
+fn spans_and_header_rows_without_thead() {
+    let table = parse_table(
+        "<table><tr><th colspan=\"2\">Both</th></tr>\
+        <tr><td rowspan=\"2\" align=\"center\">Left</td><td>Right</td></tr>\
+        <tr><td><p>First</p><p>Second</p></td></tr>\
+        <tr><th>Row header</th><td>x</td></tr></table>",
+    );
+    assert_eq!(
+        table,
+        Table {
+            header: vec![vec![cell("Both").with_header(true).with_span(2, 1)]],
+            body: vec![
+                vec![
+                    cell("Left")
+                        .with_alignment(Some(TableAlignment::Center))
+                        .with_span(1, 2),
+                    cell("Right"),
+                ],
+                vec![TableCell::new(vec![
+                    TextAtomic::simple(&"First"),
+                    TextAtomic::simple(&" "),
+                    TextAtomic::simple(&"Second"),
+                ])],
+                vec![cell("Row header").with_header(true), cell("x")],
+            ],
+        }
+    );
+}
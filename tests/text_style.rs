@@ -0,0 +1,85 @@
+mod common;
+
+use pretty_assertions::assert_eq;
+use rust_html_doc_parse::{BlockContainer, TextAtomic, TextAtomicBuilder};
+
+fn first_paragraph(docblock: &str) -> Vec<TextAtomic> {
+    let html = common::rustdoc_page(&format!("<div class=\"docblock\">{}</div>", docblock));
+    let page = rust_html_doc_parse::parse_html(&html).unwrap();
+    page.content
+        .0
+        .into_iter()
+        .find_map(|b| match b {
+            BlockContainer::Paragraph(p) => Some(p),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn simple_styles() {
+    assert_eq!(
+        first_paragraph(
+            "<p>a <strong>b</strong> <em>c</em> <del>d</del> <u>e</u> <b>f</b> <i>g</i> <s>h</s></p>"
+        ),
+        vec![
+            TextAtomic::simple(&"a "),
+            TextAtomicBuilder::new(&"b").bold(true).build(),
+            TextAtomic::simple(&" "),
+            TextAtomicBuilder::new(&"c").italic(true).build(),
+            TextAtomic::simple(&" "),
+            TextAtomicBuilder::new(&"d").strike_through(true).build(),
+            TextAtomic::simple(&" "),
+            TextAtomicBuilder::new(&"e").underline(true).build(),
+            TextAtomic::simple(&" "),
+            TextAtomicBuilder::new(&"f").bold(true).build(),
+            TextAtomic::simple(&" "),
+            TextAtomicBuilder::new(&"g").italic(true).build(),
+            TextAtomic::simple(&" "),
+            TextAtomicBuilder::new(&"h").strike_through(true).build(),
+        ]
+    );
+}
+
+#[test]
+fn nested_styles_are_combined() {
+    assert_eq!(
+        first_paragraph("<p><em>a <strong>b <del>c</del></strong></em></p>"),
+        vec![
+            TextAtomicBuilder::new(&"a ").italic(true).build(),
+            TextAtomicBuilder::new(&"b ")
+                .italic(true)
+                .bold(true)
+                .build(),
+            TextAtomicBuilder::new(&"c")
+                .italic(true)
+                .bold(true)
+                .strike_through(true)
+                .build(),
+        ]
+    );
+}
+
+#[test]
+fn code_inside_links() {
+    assert_eq!(
+        first_paragraph(
+            "<p>See <a href=\"struct.Vec.html\"><code>Vec</code></a> \
+            and <code><a href=\"enum.Option.html\">Option</a>&lt;T&gt;</code>.</p>"
+        ),
+        vec![
+            TextAtomic::simple(&"See "),
+            TextAtomicBuilder::new(&"Vec")
+                .code(true)
+                .set_url(&"struct.Vec.html")
+                .build(),
+            TextAtomic::simple(&" and "),
+            TextAtomicBuilder::new(&"Option")
+                .code(true)
+                .set_url(&"enum.Option.html")
+                .build(),
+            TextAtomicBuilder::new(&"<T>").code(true).build(),
+            TextAtomic::simple(&"."),
+        ]
+    );
+}